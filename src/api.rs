@@ -1,10 +1,35 @@
+pub mod ads;
+pub mod automod;
+pub mod bits;
+pub mod blocked_terms;
+pub mod channels;
+pub mod charity;
+pub mod clips;
+pub mod followers;
+pub mod games;
+pub mod goals;
+pub mod guest_star;
+pub mod hype_train;
+pub mod moderation;
+pub mod polls;
+pub mod predictions;
+pub mod raids;
 pub mod responses;
+pub mod rewards;
+pub mod roles;
+pub mod schedule;
+pub mod streams;
+pub mod subscriptions;
+pub mod teams;
+pub mod users;
+pub mod videos;
 
-use log::error;
-use serde::de::DeserializeOwned;
+use serde::de::{DeserializeOwned, IgnoredAny};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::eventsub::events::prefixed_user;
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 type Result<T> = std::result::Result<T, APIError>;
@@ -21,6 +46,26 @@ pub enum APIError {
   Forbidden,
   #[error("request is missing pagination information")]
   NoPagination,
+  #[error("request was rejected by twitch: {0}")]
+  BadRequest(String),
+  #[error("requested resource was not found: {0}")]
+  NotFound(String),
+  #[error("request conflicts with another operation: {0}")]
+  Conflict(String),
+  #[error("too many requests, rate limit exceeded")]
+  TooManyRequests,
+  #[error("the user is already banned")]
+  AlreadyBanned,
+  #[error("the user is not banned")]
+  NotBanned,
+  #[error("the user may not be banned or timed out")]
+  CannotBeBanned,
+  #[error("request exceeds twitch limits: {0}")]
+  InvalidRequest(String),
+  #[error("the broadcaster is already raiding another channel")]
+  AlreadyRaiding,
+  #[error("the broadcaster has no pending raid")]
+  NoPendingRaid,
 }
 
 impl From<ureq::Error> for APIError {
@@ -29,8 +74,20 @@ impl From<ureq::Error> for APIError {
     match value {
       E::Status(401, _) => APIError::Unauthorized,
       E::Status(403, _) => APIError::Forbidden,
-      E::Status(code, _) => APIError::ConnectionError(format!("status code: {code}")),
-      E::Transport(_) => APIError::ConnectionError(format!("failed to reach twitch")),
+      E::Status(429, _) => APIError::TooManyRequests,
+      E::Status(code, response) => {
+        let message = response
+          .into_json::<ErrorResponse>()
+          .map(|e| e.message)
+          .unwrap_or_else(|_| format!("status code: {code}"));
+        match code {
+          400 => APIError::BadRequest(message),
+          404 => APIError::NotFound(message),
+          409 => APIError::Conflict(message),
+          _ => APIError::ConnectionError(message),
+        }
+      }
+      E::Transport(_) => APIError::ConnectionError("failed to reach twitch".to_owned()),
     }
   }
 }
@@ -41,12 +98,74 @@ impl From<std::io::Error> for APIError {
   }
 }
 
+#[derive(Deserialize, Debug)]
+struct ErrorResponse {
+  message: String,
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum APIEndpoint {
   Token,
   Subscriptions,
   Websocket,
+  Bans,
+  BannedUsers,
+  ChatMessages,
+  AutomodMessage,
+  AutomodStatus,
+  AutomodSettings,
+  BlockedTerms,
+  Moderators,
+  Vips,
+  ShieldMode,
+  Warnings,
+  SuspiciousUsers,
+  UnbanRequests,
+  CustomRewards,
+  Redemptions,
+  Polls,
+  Predictions,
+  Raids,
+  Users,
+  Channels,
+  SearchChannels,
+  Games,
+  SearchCategories,
+  Streams,
+  FollowedStreams,
+  Commercial,
+  AdSchedule,
+  SnoozeAd,
+  Clips,
+  StreamMarkers,
+  Videos,
+  Schedule,
+  ScheduleSegment,
+  ScheduleSettings,
+  ScheduleICalendar,
+  BroadcasterSubscriptions,
+  UserSubscription,
+  BitsLeaderboard,
+  Cheermotes,
+  ChannelFollowers,
+  FollowedChannels,
+  CharityCampaigns,
+  CharityDonations,
+  Goals,
+  HypeTrainEvents,
+  GuestStarSettings,
+  GuestStarSession,
+  GuestStarInvites,
+  GuestStarSlot,
+  GuestStarSlotSettings,
+  UserBlocks,
+  ChatColor,
+  ChannelEditors,
+  ModeratedChannels,
+  Teams,
+  ChannelTeams,
 }
 
 impl APIEndpoint {
@@ -55,16 +174,88 @@ impl APIEndpoint {
       APIEndpoint::Token => "https://id.twitch.tv/oauth2/token",
       APIEndpoint::Subscriptions => "https://api.twitch.tv/helix/eventsub/subscriptions",
       APIEndpoint::Websocket => "wss://eventsub.wss.twitch.tv/ws",
+      APIEndpoint::Bans => "https://api.twitch.tv/helix/moderation/bans",
+      APIEndpoint::BannedUsers => "https://api.twitch.tv/helix/moderation/banned",
+      APIEndpoint::ChatMessages => "https://api.twitch.tv/helix/moderation/chat",
+      APIEndpoint::AutomodMessage => "https://api.twitch.tv/helix/moderation/automod/message",
+      APIEndpoint::AutomodStatus => "https://api.twitch.tv/helix/moderation/enforcements/status",
+      APIEndpoint::AutomodSettings => "https://api.twitch.tv/helix/moderation/automod/settings",
+      APIEndpoint::BlockedTerms => "https://api.twitch.tv/helix/moderation/blocked_terms",
+      APIEndpoint::Moderators => "https://api.twitch.tv/helix/moderation/moderators",
+      APIEndpoint::Vips => "https://api.twitch.tv/helix/channels/vips",
+      APIEndpoint::ShieldMode => "https://api.twitch.tv/helix/moderation/shield_mode",
+      APIEndpoint::Warnings => "https://api.twitch.tv/helix/moderation/warnings",
+      APIEndpoint::SuspiciousUsers => "https://api.twitch.tv/helix/moderation/suspicious_users",
+      APIEndpoint::UnbanRequests => "https://api.twitch.tv/helix/moderation/unban_requests",
+      APIEndpoint::CustomRewards => "https://api.twitch.tv/helix/channel_points/custom_rewards",
+      APIEndpoint::Redemptions =>
+        "https://api.twitch.tv/helix/channel_points/custom_rewards/redemptions",
+      APIEndpoint::Polls => "https://api.twitch.tv/helix/polls",
+      APIEndpoint::Predictions => "https://api.twitch.tv/helix/predictions",
+      APIEndpoint::Raids => "https://api.twitch.tv/helix/raids",
+      APIEndpoint::Users => "https://api.twitch.tv/helix/users",
+      APIEndpoint::Channels => "https://api.twitch.tv/helix/channels",
+      APIEndpoint::SearchChannels => "https://api.twitch.tv/helix/search/channels",
+      APIEndpoint::Games => "https://api.twitch.tv/helix/games",
+      APIEndpoint::SearchCategories => "https://api.twitch.tv/helix/search/categories",
+      APIEndpoint::Streams => "https://api.twitch.tv/helix/streams",
+      APIEndpoint::FollowedStreams => "https://api.twitch.tv/helix/streams/followed",
+      APIEndpoint::Commercial => "https://api.twitch.tv/helix/channels/commercial",
+      APIEndpoint::AdSchedule => "https://api.twitch.tv/helix/channels/ads",
+      APIEndpoint::SnoozeAd => "https://api.twitch.tv/helix/channels/ads/schedule/snooze",
+      APIEndpoint::Clips => "https://api.twitch.tv/helix/clips",
+      APIEndpoint::StreamMarkers => "https://api.twitch.tv/helix/streams/markers",
+      APIEndpoint::Videos => "https://api.twitch.tv/helix/videos",
+      APIEndpoint::Schedule => "https://api.twitch.tv/helix/schedule",
+      APIEndpoint::ScheduleSegment => "https://api.twitch.tv/helix/schedule/segment",
+      APIEndpoint::ScheduleSettings => "https://api.twitch.tv/helix/schedule/settings",
+      APIEndpoint::ScheduleICalendar => "https://api.twitch.tv/helix/schedule/icalendar",
+      APIEndpoint::BroadcasterSubscriptions => "https://api.twitch.tv/helix/subscriptions",
+      APIEndpoint::UserSubscription => "https://api.twitch.tv/helix/subscriptions/user",
+      APIEndpoint::BitsLeaderboard => "https://api.twitch.tv/helix/bits/leaderboard",
+      APIEndpoint::Cheermotes => "https://api.twitch.tv/helix/bits/cheermotes",
+      APIEndpoint::ChannelFollowers => "https://api.twitch.tv/helix/channels/followers",
+      APIEndpoint::FollowedChannels => "https://api.twitch.tv/helix/channels/followed",
+      APIEndpoint::CharityCampaigns => "https://api.twitch.tv/helix/charity/campaigns",
+      APIEndpoint::CharityDonations => "https://api.twitch.tv/helix/charity/donations",
+      APIEndpoint::Goals => "https://api.twitch.tv/helix/goals",
+      APIEndpoint::HypeTrainEvents => "https://api.twitch.tv/helix/hypetrain/events",
+      APIEndpoint::GuestStarSettings => "https://api.twitch.tv/helix/guest_star/channel_settings",
+      APIEndpoint::GuestStarSession => "https://api.twitch.tv/helix/guest_star/session",
+      APIEndpoint::GuestStarInvites => "https://api.twitch.tv/helix/guest_star/invites",
+      APIEndpoint::GuestStarSlot => "https://api.twitch.tv/helix/guest_star/slot",
+      APIEndpoint::GuestStarSlotSettings => "https://api.twitch.tv/helix/guest_star/slot_settings",
+      APIEndpoint::UserBlocks => "https://api.twitch.tv/helix/users/blocks",
+      APIEndpoint::ChatColor => "https://api.twitch.tv/helix/chat/color",
+      APIEndpoint::ChannelEditors => "https://api.twitch.tv/helix/channels/editors",
+      APIEndpoint::ModeratedChannels => "https://api.twitch.tv/helix/moderation/channels",
+      APIEndpoint::Teams => "https://api.twitch.tv/helix/teams",
+      APIEndpoint::ChannelTeams => "https://api.twitch.tv/helix/teams/channel",
     }
   }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+type Query<'a> = &'a [(&'a str, &'a str)];
+
 #[derive(Deserialize, Debug)]
 struct DataList<T> {
+  data: Vec<T>,
+}
+
+#[derive(Deserialize, Debug)]
+struct PagedList<T, M> {
   data:       Vec<T>,
   pagination: Option<Pagination>,
+  #[serde(flatten)]
+  totals:     M,
+}
+
+#[derive(Deserialize, Debug)]
+struct Data<T> {
+  data:       T,
+  pagination: Option<Pagination>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -72,6 +263,27 @@ struct Pagination {
   cursor: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Method {
+  Get,
+  Post,
+  Patch,
+  Put,
+  Delete,
+}
+
+impl Method {
+  fn as_str(self) -> &'static str {
+    match self {
+      Method::Get => "GET",
+      Method::Post => "POST",
+      Method::Patch => "PATCH",
+      Method::Put => "PUT",
+      Method::Delete => "DELETE",
+    }
+  }
+}
+
 pub(super) struct TwitchAPI {
   client_id: String,
   token:     String,
@@ -82,57 +294,185 @@ impl TwitchAPI {
     Self { client_id, token }
   }
 
-  pub(super) fn get<T: DeserializeOwned>(&self, endpoint: APIEndpoint) -> Result<Vec<T>> {
-    let mut parsed_subscriptions = Vec::new();
-    let mut cursor = String::new();
+  pub(super) fn get<T: DeserializeOwned>(
+    &self,
+    endpoint: APIEndpoint,
+    query: Query,
+  ) -> Result<Vec<T>> {
+    Ok(self.get_with_totals::<T, IgnoredAny>(endpoint, query)?.0)
+  }
+
+  // Like `get`, but also returns the fields next to `data` of the first page, e.g. `total`.
+  pub(super) fn get_with_totals<T, M>(
+    &self,
+    endpoint: APIEndpoint,
+    query: Query,
+  ) -> Result<(Vec<T>, M)>
+  where
+    T: DeserializeOwned,
+    M: DeserializeOwned,
+  {
+    let PagedList { data: mut parsed_objects, pagination, totals } =
+      self.request(Method::Get, endpoint, query).parse()?;
+    let mut cursor = pagination.ok_or(APIError::NoPagination)?.cursor;
+
+    while let Some(after) = cursor {
+      let mut objects = self
+        .request(Method::Get, endpoint, query)
+        .after(Some(&after))
+        .parse::<PagedList<T, IgnoredAny>>()?;
+      parsed_objects.append(&mut objects.data);
+      cursor = objects.pagination.ok_or(APIError::NoPagination)?.cursor;
+    }
 
-    loop {
-      let mut subscriptions = ureq::get(endpoint.endpoint())
-        .set("Authorization", &format!("Bearer {}", self.token))
-        .set("Client-Id", &self.client_id)
-        .query("after", &cursor)
-        .call()?
-        .into_json::<DataList<T>>()?;
+    Ok((parsed_objects, totals))
+  }
 
-      parsed_subscriptions.append(&mut subscriptions.data);
+  // Like `get`, but stops paging once `limit` objects were fetched, for endpoints whose filters
+  // can match an unbounded number of objects.
+  pub(super) fn get_limited<T: DeserializeOwned>(
+    &self,
+    endpoint: APIEndpoint,
+    query: Query,
+    limit: usize,
+  ) -> Result<Vec<T>> {
+    let first = limit.clamp(1, 100).to_string();
+    let mut query = query.to_vec();
+    query.push(("first", &first));
 
-      match subscriptions.pagination.ok_or(APIError::NoPagination)?.cursor {
-        Some(new_cursor) => cursor = new_cursor,
-        None => break,
+    let mut parsed_objects = Vec::new();
+    let mut cursor = None;
+    while parsed_objects.len() < limit {
+      let page = self
+        .request(Method::Get, endpoint, &query)
+        .after(cursor.as_deref())
+        .parse::<PagedList<T, IgnoredAny>>()?;
+      parsed_objects.extend(page.data);
+      cursor = page.pagination.ok_or(APIError::NoPagination)?.cursor;
+      if cursor.is_none() {
+        break;
       }
     }
+    parsed_objects.truncate(limit);
 
-    Ok(parsed_subscriptions)
+    Ok(parsed_objects)
   }
 
-  pub(super) fn post<R, T>(&self, endpoint: APIEndpoint, data: T) -> Result<R>
+  pub(super) fn get_list_with_totals<T, M>(
+    &self,
+    endpoint: APIEndpoint,
+    query: Query,
+  ) -> Result<(Vec<T>, M)>
   where
-    R: DeserializeOwned,
-    T: Serialize,
+    T: DeserializeOwned,
+    M: DeserializeOwned,
   {
-    let mut response = ureq::post(endpoint.endpoint())
-      .set("Authorization", &format!("Bearer {}", self.token))
-      .set("Client-Id", &self.client_id)
-      .set("Content-Type", "application/json")
-      .send_json(data)?
-      .into_json::<DataList<R>>()?;
+    let page = self.request(Method::Get, endpoint, query).parse::<PagedList<T, M>>()?;
+    Ok((page.data, page.totals))
+  }
 
-    match response.data.pop() {
-      Some(object) => Ok(object),
-      None => Err(APIError::ParseError("data missing in response".to_owned())),
-    }
+  // Some endpoints return a single object whose contents are paginated, so the cursor is returned
+  // for the caller to merge pages.
+  pub(super) fn get_page<T: DeserializeOwned>(
+    &self,
+    endpoint: APIEndpoint,
+    query: Query,
+    cursor: Option<&str>,
+  ) -> Result<(T, Option<String>)> {
+    let response = self.request(Method::Get, endpoint, query).after(cursor).parse::<Data<T>>()?;
+
+    Ok((response.data, response.pagination.and_then(|p| p.cursor)))
   }
 
-  pub(super) fn delete(&self, endpoint: APIEndpoint, id: &str) {
-    let delete = ureq::delete(&endpoint.endpoint())
+  pub(super) fn request(&self, method: Method, endpoint: APIEndpoint, query: Query) -> Request {
+    let request = ureq::request(method.as_str(), endpoint.endpoint())
       .set("Authorization", &format!("Bearer {}", self.token))
       .set("Client-Id", &self.client_id)
-      .query("id", id)
-      .call();
+      .query_pairs(query.iter().copied());
 
-    match delete {
-      Ok(_) => (),
-      Err(e) => error!("failed to delete object: {e}"),
+    Request { request, body: None }
+  }
+}
+
+// A request to a Helix endpoint, optionally with a JSON body, finished by the method matching the
+// shape of the response.
+pub(super) struct Request<T = ()> {
+  request: ureq::Request,
+  body:    Option<T>,
+}
+
+impl Request {
+  pub(super) fn json<T: Serialize>(self, data: T) -> Request<T> {
+    Request { request: self.request, body: Some(data) }
+  }
+}
+
+impl<T: Serialize> Request<T> {
+  // Endpoints responding with `{ "data": [...] }`.
+  pub(super) fn list<R: DeserializeOwned>(self) -> Result<Vec<R>> {
+    Ok(self.parse::<DataList<R>>()?.data)
+  }
+
+  // Endpoints responding with a single object wrapped in a list.
+  pub(super) fn one<R: DeserializeOwned>(self) -> Result<R> {
+    first(self.list()?)
+  }
+
+  // Endpoints responding with `{ "data": {...} }`.
+  pub(super) fn object<R: DeserializeOwned>(self) -> Result<R> {
+    Ok(self.parse::<Data<R>>()?.data)
+  }
+
+  pub(super) fn text(self) -> Result<String> {
+    Ok(self.send()?.into_string()?)
+  }
+
+  pub(super) fn empty(self) -> Result<()> {
+    self.send()?;
+    Ok(())
+  }
+
+  fn after(mut self, cursor: Option<&str>) -> Self {
+    if let Some(cursor) = cursor {
+      self.request = self.request.query("after", cursor);
     }
+    self
+  }
+
+  fn parse<R: DeserializeOwned>(self) -> Result<R> {
+    Ok(self.send()?.into_json::<R>()?)
+  }
+
+  fn send(self) -> Result<ureq::Response> {
+    Ok(match self.body {
+      Some(data) => self.request.set("Content-Type", "application/json").send_json(data)?,
+      None => self.request.call()?,
+    })
   }
 }
+
+fn query_value<T: Serialize>(value: &T) -> String {
+  match serde_json::to_value(value) {
+    Ok(serde_json::Value::String(value)) => value,
+    Ok(value) => value.to_string(),
+    Err(_) => String::new(),
+  }
+}
+
+fn first<T>(mut data: Vec<T>) -> Result<T> {
+  match data.pop() {
+    Some(object) => Ok(object),
+    None => Err(APIError::ParseError("data missing in response".to_owned())),
+  }
+}
+
+// Deserialization helpers
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+prefixed_user!(broadcaster "broadcaster_id" "broadcaster_login" "broadcaster_name");
+prefixed_user!(moderator "moderator_id" "moderator_login" "moderator_name");
+prefixed_user!(gifter "gifter_id" "gifter_login" "gifter_name");
+prefixed_user!(account "id" "login" "display_name");
+prefixed_user!(found_channel "id" "broadcaster_login" "display_name");
+prefixed_user!(guest "user_id" "user_login" "user_display_name");
+prefixed_user!(blocked_user "user_id" "user_login" "display_name");
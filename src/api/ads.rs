@@ -0,0 +1,348 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use super::{APIEndpoint, Method};
+use crate::{Event, Twitch, TwitchError, maybe_string};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Requires `Scope::ChannelEditCommercial`.
+  pub fn start_commercial(
+    &self,
+    broadcaster_id: &str,
+    length: u32,
+  ) -> Result<Commercial, TwitchError> {
+    let request = StartCommercial { broadcaster_id, length };
+
+    Ok(self.api.request(Method::Post, APIEndpoint::Commercial, &[]).json(request).one()?)
+  }
+
+  /// Requires `Scope::ChannelReadAds`.
+  pub fn get_ad_schedule(&self, broadcaster_id: &str) -> Result<AdSchedule, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id)];
+
+    Ok(self.api.request(Method::Get, APIEndpoint::AdSchedule, &query).one()?)
+  }
+
+  /// Requires `Scope::ChannelManageAds`.
+  pub fn snooze_next_ad(&self, broadcaster_id: &str) -> Result<SnoozedAd, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id)];
+
+    Ok(self.api.request(Method::Post, APIEndpoint::SnoozeAd, &query).one()?)
+  }
+
+  /// Requires `Scope::ChannelReadAds`.
+  pub fn get_ad_scheduler(&self, broadcaster_id: &str) -> Result<AdScheduler, TwitchError> {
+    let schedule = self.get_ad_schedule(broadcaster_id)?;
+
+    Ok(AdScheduler::new(broadcaster_id.to_owned(), schedule))
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Debug)]
+struct StartCommercial<'a> {
+  broadcaster_id: &'a str,
+  length:         u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Commercial {
+  pub length:      u32,
+  pub message:     String,
+  pub retry_after: u32,
+}
+
+/// Timestamps are RFC3339 in UTC, `None` when there is no such ad.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AdSchedule {
+  #[serde(deserialize_with = "timestamp")]
+  pub next_ad_at:        Option<String>,
+  #[serde(deserialize_with = "timestamp")]
+  pub last_ad_at:        Option<String>,
+  #[serde(deserialize_with = "maybe_string")]
+  pub duration:          u64,
+  #[serde(deserialize_with = "maybe_string")]
+  pub preroll_free_time: u32,
+  #[serde(deserialize_with = "maybe_string")]
+  pub snooze_count:      u32,
+  #[serde(deserialize_with = "timestamp")]
+  pub snooze_refresh_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SnoozedAd {
+  #[serde(deserialize_with = "maybe_string")]
+  pub snooze_count:      u32,
+  #[serde(deserialize_with = "timestamp")]
+  pub snooze_refresh_at: Option<String>,
+  #[serde(deserialize_with = "timestamp")]
+  pub next_ad_at:        Option<String>,
+}
+
+// The ads endpoints document RFC3339 timestamps but return unix timestamps, so both are accepted and
+// converted to the same UTC format. Missing ads are sent as an empty string or zero.
+fn timestamp<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+  #[derive(Deserialize)]
+  #[serde(untagged)]
+  enum Timestamp {
+    String(String),
+    Unix(u64),
+  }
+
+  Ok(match Timestamp::deserialize(deserializer)? {
+    Timestamp::String(timestamp) if timestamp.is_empty() => None,
+    Timestamp::String(timestamp) => match timestamp.parse::<u64>() {
+      Ok(0) => None,
+      Ok(seconds) => Some(rfc3339(seconds)),
+      Err(_) => match unix_seconds(&timestamp) {
+        Some(seconds) => Some(rfc3339(seconds)),
+        None => return Err(D::Error::custom(format!("invalid timestamp {timestamp}"))),
+      },
+    },
+    Timestamp::Unix(0) => None,
+    Timestamp::Unix(seconds) => Some(rfc3339(seconds)),
+  })
+}
+
+// Accepts RFC3339 timestamps with any offset, fractional seconds are dropped.
+fn unix_seconds(timestamp: &str) -> Option<u64> {
+  let number = |value: &str| value.parse::<i64>().ok();
+  let (date, time) = timestamp.split_once(['T', 't'])?;
+  let offset_at = time.find(['Z', 'z', '+', '-'])?;
+  let (time, offset) = time.split_at(offset_at);
+
+  let mut date = date.splitn(3, '-').map(number);
+  let (year, month, day) = (date.next()??, date.next()??, date.next()??);
+  let mut time = time.split('.').next()?.splitn(3, ':').map(number);
+  let (hour, minute, second) = (time.next()??, time.next()??, time.next()??);
+  if !(1..=12).contains(&month)
+    || !(1..=31).contains(&day)
+    || hour > 23
+    || minute > 59
+    || second > 60
+  {
+    return None;
+  }
+
+  let offset = match offset {
+    "Z" | "z" => 0,
+    _ => {
+      let (hours, minutes) = offset[1..].split_once(':')?;
+      let seconds = number(hours)? * 3600 + number(minutes)? * 60;
+      if offset.starts_with('-') { -seconds } else { seconds }
+    }
+  };
+
+  // Days since the unix epoch from a civil date, the inverse of `rfc3339` below.
+  let year = if month <= 2 { year - 1 } else { year };
+  let era = year.div_euclid(400);
+  let year_of_era = year - era * 400;
+  let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+  let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+  let days = era * 146097 + day_of_era - 719468;
+
+  u64::try_from(days * 86400 + hour * 3600 + minute * 60 + second - offset).ok()
+}
+
+fn now() -> u64 {
+  SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.as_secs())
+}
+
+// Civil date from days since the unix epoch, see http://howardhinnant.github.io/date_algorithms.html
+fn rfc3339(seconds: u64) -> String {
+  let (days, time) = (seconds / 86400, seconds % 86400);
+  let z = days + 719468;
+  let era = z / 146097;
+  let day_of_era = z - era * 146097;
+  let year_of_era =
+    (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+  let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let month_index = (5 * day_of_year + 2) / 153;
+  let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+  let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+  let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+  format!(
+    "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+    time / 3600,
+    time % 3600 / 60,
+    time % 60
+  )
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Ad schedule of a single channel, kept up to date by snoozes and eventsub ad break events.
+#[derive(Debug, Clone)]
+pub struct AdScheduler {
+  broadcaster_id:   String,
+  schedule:         AdSchedule,
+  // Unix time at which the last ad break seen through eventsub ends.
+  ad_break_ends_at: Option<u64>,
+}
+
+impl AdScheduler {
+  pub fn new(broadcaster_id: String, schedule: AdSchedule) -> Self {
+    Self { broadcaster_id, schedule, ad_break_ends_at: None }
+  }
+
+  pub fn broadcaster_id(&self) -> &str {
+    &self.broadcaster_id
+  }
+
+  pub fn is_ad_running(&self) -> bool {
+    self.ad_break_ends_at.is_some_and(|ends_at| now() < ends_at)
+  }
+
+  /// Unknown while an ad break is running. Afterwards this is the time from the last schedule or
+  /// snooze, which may already have passed until the schedule is refreshed.
+  pub fn next_ad_at(&self) -> Option<&str> {
+    if self.is_ad_running() { None } else { self.schedule.next_ad_at.as_deref() }
+  }
+
+  pub fn last_ad_at(&self) -> Option<&str> {
+    self.schedule.last_ad_at.as_deref()
+  }
+
+  pub fn duration(&self) -> u64 {
+    self.schedule.duration
+  }
+
+  pub fn preroll_free_time(&self) -> u32 {
+    self.schedule.preroll_free_time
+  }
+
+  pub fn snooze_count(&self) -> u32 {
+    self.schedule.snooze_count
+  }
+
+  pub fn snooze_refresh_at(&self) -> Option<&str> {
+    self.schedule.snooze_refresh_at.as_deref()
+  }
+
+  pub fn update_schedule(&mut self, schedule: AdSchedule) {
+    self.schedule = schedule;
+    self.ad_break_ends_at = None;
+  }
+
+  pub fn update_snooze(&mut self, snooze: &SnoozedAd) {
+    self.schedule.snooze_count = snooze.snooze_count;
+    self.schedule.snooze_refresh_at = snooze.snooze_refresh_at.clone();
+    self.schedule.next_ad_at = snooze.next_ad_at.clone();
+  }
+
+  pub fn handle_event(&mut self, event: &Event) {
+    if let Event::AdBreakBegin(ad) = event
+      && ad.broadcaster.user_id == self.broadcaster_id
+    {
+      let started_at = unix_seconds(&ad.started_at).unwrap_or_else(now);
+      self.schedule.last_ad_at = Some(rfc3339(started_at));
+      self.schedule.duration = ad.duration_seconds;
+      self.ad_break_ends_at = Some(started_at + ad.duration_seconds);
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::eventsub::events::notification;
+
+  #[test]
+  fn start_commercial() {
+    let response = r#"
+    {
+      "length": 60,
+      "message": "",
+      "retry_after": 480
+    }"#;
+    serde_json::from_str::<Commercial>(response).unwrap();
+  }
+
+  #[test]
+  fn get_ad_schedule() {
+    let response = r#"
+    {
+      "next_ad_at": "2023-08-01T23:08:18+00:00",
+      "last_ad_at": "2023-08-01T23:08:18+00:00",
+      "duration": "60",
+      "preroll_free_time": "90",
+      "snooze_count": "1",
+      "snooze_refresh_at": "2023-08-02T01:08:18.123+02:00"
+    }"#;
+    let schedule = serde_json::from_str::<AdSchedule>(response).unwrap();
+    assert_eq!(schedule.next_ad_at.as_deref(), Some("2023-08-01T23:08:18Z"));
+    assert_eq!(schedule.snooze_refresh_at.as_deref(), Some("2023-08-01T23:08:18Z"));
+
+    let response = r#"
+    {
+      "next_ad_at": 1690931298,
+      "last_ad_at": 1690927698,
+      "duration": 60,
+      "preroll_free_time": 90,
+      "snooze_count": 1,
+      "snooze_refresh_at": 0
+    }"#;
+    let schedule = serde_json::from_str::<AdSchedule>(response).unwrap();
+    assert_eq!(schedule.next_ad_at.as_deref(), Some("2023-08-01T23:08:18Z"));
+    assert_eq!(schedule.snooze_refresh_at, None);
+  }
+
+  #[test]
+  fn ad_scheduler() {
+    let schedule = r#"
+    {
+      "next_ad_at": "2023-08-01T23:08:18+00:00",
+      "last_ad_at": "2023-08-01T22:08:18+00:00",
+      "duration": "60",
+      "preroll_free_time": "90",
+      "snooze_count": "1",
+      "snooze_refresh_at": "2023-08-01T23:08:18+00:00"
+    }"#;
+    let mut scheduler =
+      AdScheduler::new("1337".to_owned(), serde_json::from_str(schedule).unwrap());
+
+    let snooze = r#"
+    {
+      "snooze_count": "0",
+      "snooze_refresh_at": "2023-08-01T23:08:18+00:00",
+      "next_ad_at": "2023-08-01T23:13:18+00:00"
+    }"#;
+    scheduler.update_snooze(&serde_json::from_str(snooze).unwrap());
+    assert_eq!(scheduler.snooze_count(), 0);
+    assert_eq!(scheduler.next_ad_at(), Some("2023-08-01T23:13:18Z"));
+
+    let event = |started_at: &str| {
+      let event = r#"
+      {
+        "duration_seconds": "90",
+        "started_at": "STARTED_AT",
+        "is_automatic": "false",
+        "broadcaster_user_id": "1337",
+        "broadcaster_user_login": "cool_user",
+        "broadcaster_user_name": "Cool_User",
+        "requester_user_id": "1337",
+        "requester_user_login": "cool_user",
+        "requester_user_name": "Cool_User"
+      }"#;
+      notification("channel.ad_break.begin", &event.replace("STARTED_AT", started_at))
+    };
+
+    scheduler.handle_event(&event(&rfc3339(now())));
+    assert_eq!(scheduler.duration(), 90);
+    assert!(scheduler.is_ad_running());
+    assert_eq!(scheduler.next_ad_at(), None);
+
+    scheduler.handle_event(&event("2023-08-01T23:13:18+00:00"));
+    assert!(!scheduler.is_ad_running());
+    assert_eq!(scheduler.last_ad_at(), Some("2023-08-01T23:13:18Z"));
+    assert_eq!(scheduler.next_ad_at(), Some("2023-08-01T23:13:18Z"));
+  }
+}
@@ -0,0 +1,184 @@
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, Method};
+use crate::{AutomodSettingsUpdate, Twitch, TwitchError};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Requires `Scope::ModeratorManageAutomod`.
+  pub fn manage_held_automod_message(
+    &self,
+    moderator_id: &str,
+    message_id: &str,
+    action: AutomodDecision,
+  ) -> Result<(), TwitchError> {
+    let request = ManageHeldMessage { user_id: moderator_id, msg_id: message_id, action };
+
+    Ok(self.api.request(Method::Post, APIEndpoint::AutomodMessage, &[]).json(request).empty()?)
+  }
+
+  /// Requires `Scope::ModerationRead`.
+  pub fn check_automod_status(
+    &self,
+    broadcaster_id: &str,
+    messages: &[AutomodCheck],
+  ) -> Result<Vec<AutomodStatus>, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id)];
+    let request = CheckStatusRequest { data: messages };
+
+    Ok(self.api.request(Method::Post, APIEndpoint::AutomodStatus, &query).json(request).list()?)
+  }
+
+  /// Requires `Scope::ModeratorReadAutomodSettings`.
+  pub fn get_automod_settings(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+  ) -> Result<AutomodSettings, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("moderator_id", moderator_id)];
+
+    Ok(self.api.request(Method::Get, APIEndpoint::AutomodSettings, &query).one()?)
+  }
+
+  /// Requires `Scope::ModeratorManageAutomodSettings`.
+  pub fn update_automod_settings(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    settings: AutomodSettingsChange,
+  ) -> Result<AutomodSettings, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("moderator_id", moderator_id)];
+
+    Ok(self.api.request(Method::Put, APIEndpoint::AutomodSettings, &query).json(settings).one()?)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Debug)]
+struct ManageHeldMessage<'a> {
+  user_id: &'a str,
+  msg_id:  &'a str,
+  action:  AutomodDecision,
+}
+
+#[derive(Serialize, Debug)]
+struct CheckStatusRequest<'a> {
+  data: &'a [AutomodCheck],
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AutomodDecision {
+  Allow,
+  Deny,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AutomodCheck {
+  pub msg_id:   String,
+  pub msg_text: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AutomodStatus {
+  pub msg_id:       String,
+  pub is_permitted: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AutomodSettings {
+  pub broadcaster_id: String,
+  pub moderator_id:   String,
+  pub overall_level:  Option<u32>,
+  #[serde(flatten)]
+  pub levels:         AutomodLevels,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct AutomodLevels {
+  pub aggression:                 u32,
+  pub bullying:                   u32,
+  pub disability:                 u32,
+  pub misogyny:                   u32,
+  pub race_ethnicity_or_religion: u32,
+  pub sex_based_terms:            u32,
+  pub sexuality_sex_or_gender:    u32,
+  pub swearing:                   u32,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum AutomodSettingsChange {
+  Overall { overall_level: u32 },
+  Individual(AutomodLevels),
+}
+
+impl From<&AutomodSettingsUpdate> for AutomodSettings {
+  fn from(event: &AutomodSettingsUpdate) -> Self {
+    Self {
+      broadcaster_id: event.broadcaster.user_id.clone(),
+      moderator_id:   event.moderator.user_id.clone(),
+      overall_level:  event.overall_level,
+      levels:         AutomodLevels {
+        aggression:                 event.aggression,
+        bullying:                   event.bullying,
+        disability:                 event.disability,
+        misogyny:                   event.misogyny,
+        race_ethnicity_or_religion: event.race_ethnicity_or_religion,
+        sex_based_terms:            event.sex_based_terms,
+        sexuality_sex_or_gender:    event.sexuality_sex_or_gender,
+        swearing:                   event.swearing,
+      },
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn check_automod_status() {
+    let response = r#"
+    {
+      "msg_id": "123",
+      "is_permitted": true
+    }"#;
+    serde_json::from_str::<AutomodStatus>(response).unwrap();
+  }
+
+  #[test]
+  fn get_automod_settings() {
+    let response = r#"
+    {
+      "broadcaster_id": "1234",
+      "moderator_id": "5678",
+      "overall_level": null,
+      "disability": 0,
+      "aggression": 0,
+      "sexuality_sex_or_gender": 0,
+      "misogyny": 0,
+      "bullying": 0,
+      "swearing": 0,
+      "race_ethnicity_or_religion": 0,
+      "sex_based_terms": 0
+    }"#;
+    serde_json::from_str::<AutomodSettings>(response).unwrap();
+  }
+
+  #[test]
+  fn automod_settings_change() {
+    let change = AutomodSettingsChange::Overall { overall_level: 3 };
+    assert_eq!(serde_json::to_string(&change).unwrap(), r#"{"overall_level":3}"#);
+
+    let change =
+      AutomodSettingsChange::Individual(AutomodLevels { swearing: 4, ..Default::default() });
+    let change = serde_json::to_value(&change).unwrap();
+    assert_eq!(change["swearing"], 4);
+    assert!(change.get("overall_level").is_none());
+  }
+}
@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, Method, query_value};
+use crate::{Cheermote, Twitch, TwitchError, User};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Requires `Scope::BitsRead`. At most 100 entries are returned.
+  pub fn get_bits_leaderboard(
+    &self,
+    count: u32,
+    period: BitsPeriod,
+    started_at: Option<&str>,
+    user_id: Option<&str>,
+  ) -> Result<BitsLeaderboard, TwitchError> {
+    let count = count.to_string();
+    let period = query_value(&period);
+    let mut query = vec![("count", count.as_str()), ("period", &period)];
+    query.extend(started_at.map(|time| ("started_at", time)));
+    query.extend(user_id.map(|id| ("user_id", id)));
+
+    let (entries, totals) = self
+      .api
+      .get_list_with_totals::<_, LeaderboardTotals>(APIEndpoint::BitsLeaderboard, &query)?;
+
+    Ok(BitsLeaderboard { entries, date_range: totals.date_range, total: totals.total })
+  }
+
+  /// Returns the global cheermotes, plus the custom ones of the broadcaster if one is given.
+  ///
+  /// No scope is required.
+  pub fn get_cheermotes(
+    &self,
+    broadcaster_id: Option<&str>,
+  ) -> Result<Vec<CheermoteInfo>, TwitchError> {
+    let query: Vec<_> = broadcaster_id.map(|id| ("broadcaster_id", id)).into_iter().collect();
+
+    Ok(self.api.request(Method::Get, APIEndpoint::Cheermotes, &query).list()?)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BitsPeriod {
+  Day,
+  Week,
+  Month,
+  Year,
+  All,
+}
+
+#[derive(Deserialize, Debug)]
+struct LeaderboardTotals {
+  date_range: DateRange,
+  total:      u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BitsLeaderboard {
+  pub entries:    Vec<LeaderboardEntry>,
+  pub date_range: DateRange,
+  pub total:      u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LeaderboardEntry {
+  #[serde(flatten)]
+  pub user:  User,
+  pub rank:  u32,
+  pub score: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DateRange {
+  pub started_at: String,
+  pub ended_at:   String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CheermoteInfo {
+  pub prefix:         String,
+  pub tiers:          Vec<CheermoteTier>,
+  #[serde(rename = "type")]
+  pub cheermote_type: String,
+  pub order:          u32,
+  pub last_updated:   String,
+  pub is_charitable:  bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CheermoteTier {
+  pub min_bits:          u32,
+  pub id:                String,
+  pub color:             String,
+  pub images:            CheermoteImages,
+  pub can_cheer:         bool,
+  pub show_in_bits_card: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CheermoteImages {
+  pub dark:  CheermoteImageSet,
+  pub light: CheermoteImageSet,
+}
+
+/// Image urls by scale, `"1"`, `"1.5"`, `"2"`, `"3"` and `"4"`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CheermoteImageSet {
+  pub animated: HashMap<String, String>,
+  #[serde(rename = "static")]
+  pub still:    HashMap<String, String>,
+}
+
+impl Cheermote {
+  /// Finds the tier of a cheermote chat fragment, to look up its image and colour.
+  pub fn resolve<'a>(&self, cheermotes: &'a [CheermoteInfo]) -> Option<&'a CheermoteTier> {
+    let info = cheermotes.iter().find(|c| c.prefix.eq_ignore_ascii_case(&self.prefix))?;
+
+    info.tiers.iter().find(|tier| tier.min_bits == self.tier).or_else(|| {
+      info.tiers.iter().filter(|tier| tier.min_bits <= self.bits).max_by_key(|tier| tier.min_bits)
+    })
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn get_bits_leaderboard() {
+    let response = r#"
+    {
+      "user_id": "158010205",
+      "user_login": "tundracowboy",
+      "user_name": "TundraCowboy",
+      "rank": 1,
+      "score": 12543
+    }"#;
+    serde_json::from_str::<LeaderboardEntry>(response).unwrap();
+
+    let response = r#"
+    {
+      "date_range": {
+        "started_at": "2018-02-05T08:00:00Z",
+        "ended_at": "2018-02-12T08:00:00Z"
+      },
+      "total": 2
+    }"#;
+    serde_json::from_str::<LeaderboardTotals>(response).unwrap();
+  }
+
+  #[test]
+  fn get_cheermotes() {
+    let response = r##"
+    {
+      "prefix": "Cheer",
+      "tiers": [
+        {
+          "min_bits": 1,
+          "id": "1",
+          "color": "#979797",
+          "images": {
+            "dark": {
+              "animated": {
+                "1": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/dark/animated/1/1.gif",
+                "2": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/dark/animated/1/2.gif"
+              },
+              "static": {
+                "1": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/dark/static/1/1.png"
+              }
+            },
+            "light": {
+              "animated": {
+                "1": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/light/animated/1/1.gif"
+              },
+              "static": {
+                "1": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/light/static/1/1.png"
+              }
+            }
+          },
+          "can_cheer": true,
+          "show_in_bits_card": true
+        },
+        {
+          "min_bits": 100,
+          "id": "100",
+          "color": "#9c3ee8",
+          "images": {
+            "dark": { "animated": {}, "static": {} },
+            "light": { "animated": {}, "static": {} }
+          },
+          "can_cheer": true,
+          "show_in_bits_card": true
+        }
+      ],
+      "type": "global_first_party",
+      "order": 1,
+      "last_updated": "2018-05-22T00:06:04Z",
+      "is_charitable": false
+    }"##;
+    let cheermotes = vec![serde_json::from_str::<CheermoteInfo>(response).unwrap()];
+
+    let cheermote = Cheermote { prefix: "cheer".to_owned(), bits: 250, tier: 100 };
+    assert_eq!(cheermote.resolve(&cheermotes).unwrap().color, "#9c3ee8");
+
+    let cheermote = Cheermote { prefix: "cheer".to_owned(), bits: 5, tier: 0 };
+    let tier = cheermote.resolve(&cheermotes).unwrap();
+    assert!(tier.images.dark.animated["2"].ends_with("1/2.gif"));
+  }
+}
@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, Method};
+use crate::{Action, Event, TermsAction, Twitch, TwitchError};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Requires `Scope::ModeratorReadBlockedTerms`.
+  pub fn get_blocked_terms(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+  ) -> Result<Vec<BlockedTerm>, TwitchError> {
+    let query =
+      [("broadcaster_id", broadcaster_id), ("moderator_id", moderator_id), ("first", "100")];
+
+    Ok(self.api.get(APIEndpoint::BlockedTerms, &query)?)
+  }
+
+  /// Requires `Scope::ModeratorManageBlockedTerms`.
+  pub fn add_blocked_term(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    text: &str,
+  ) -> Result<BlockedTerm, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("moderator_id", moderator_id)];
+
+    Ok(
+      self
+        .api
+        .request(Method::Post, APIEndpoint::BlockedTerms, &query)
+        .json(AddBlockedTerm { text })
+        .one()?,
+    )
+  }
+
+  /// Requires `Scope::ModeratorManageBlockedTerms`.
+  pub fn remove_blocked_term(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    id: &str,
+  ) -> Result<(), TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("moderator_id", moderator_id), ("id", id)];
+
+    Ok(self.api.request(Method::Delete, APIEndpoint::BlockedTerms, &query).empty()?)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Debug)]
+struct AddBlockedTerm<'a> {
+  text: &'a str,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BlockedTerm {
+  pub broadcaster_id: String,
+  pub moderator_id:   String,
+  pub id:             String,
+  pub text:           String,
+  pub created_at:     String,
+  pub updated_at:     String,
+  pub expires_at:     Option<String>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Local copy of a channel's blocked terms, kept up to date by feeding it eventsub events. Events
+/// of other channels are ignored.
+///
+/// Terms added through eventsub only carry their text, so their id stays unknown until the list is
+/// refreshed from [`Twitch::get_blocked_terms`] or the term is [`inserted`](Self::insert).
+#[derive(Debug, Clone)]
+pub struct BlockedTermList {
+  broadcaster_id: String,
+  terms:          HashMap<String, Option<String>>,
+}
+
+impl BlockedTermList {
+  pub fn new(broadcaster_id: String, terms: Vec<BlockedTerm>) -> Self {
+    let mut list = Self { broadcaster_id, terms: HashMap::new() };
+    terms.into_iter().for_each(|term| list.insert(term));
+    list
+  }
+
+  pub fn broadcaster_id(&self) -> &str {
+    &self.broadcaster_id
+  }
+
+  pub fn insert(&mut self, term: BlockedTerm) {
+    self.terms.insert(term.text, Some(term.id));
+  }
+
+  pub fn contains(&self, text: &str) -> bool {
+    self.terms.contains_key(text)
+  }
+
+  pub fn id(&self, text: &str) -> Option<&str> {
+    self.terms.get(text)?.as_deref()
+  }
+
+  pub fn terms(&self) -> impl Iterator<Item = &str> {
+    self.terms.keys().map(String::as_str)
+  }
+
+  pub fn len(&self) -> usize {
+    self.terms.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.terms.is_empty()
+  }
+
+  pub fn handle_event(&mut self, event: &Event) {
+    match event {
+      Event::AutomodTermsUpdate(update) if update.broadcaster.user_id != self.broadcaster_id => (),
+      Event::Moderate(moderate) if moderate.broadcaster.user_id != self.broadcaster_id => (),
+      Event::AutomodTermsUpdate(update) => match update.action {
+        TermsAction::AddBlocked => self.add(&update.terms),
+        TermsAction::RemoveBlocked => self.remove(&update.terms),
+        TermsAction::AddPermitted | TermsAction::RemovePermitted => (),
+      },
+      Event::Moderate(moderate) => match &moderate.action {
+        Action::AddBlockedTerm { automod_terms } => self.add(&automod_terms.terms),
+        Action::RemoveBlockedTerm { automod_terms } => self.remove(&automod_terms.terms),
+        _ => (),
+      },
+      _ => (),
+    }
+  }
+
+  fn add(&mut self, terms: &[String]) {
+    for term in terms {
+      self.terms.entry(term.clone()).or_default();
+    }
+  }
+
+  fn remove(&mut self, terms: &[String]) {
+    for term in terms {
+      self.terms.remove(term);
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::eventsub::events::notification;
+
+  #[test]
+  fn get_blocked_terms() {
+    let response = r#"
+    {
+      "broadcaster_id": "1234",
+      "moderator_id": "5678",
+      "id": "520e4d4e-0cda-49c7-821e-e5ef4f88c2f2",
+      "text": "A phrase I'm not fond of",
+      "created_at": "2021-09-29T19:45:37Z",
+      "updated_at": "2021-09-29T19:45:37Z",
+      "expires_at": null
+    }"#;
+    serde_json::from_str::<BlockedTerm>(response).unwrap();
+  }
+
+  #[test]
+  fn blocked_term_list() {
+    let term = r#"
+    {
+      "broadcaster_id": "1337",
+      "moderator_id": "9001",
+      "id": "520e4d4e-0cda-49c7-821e-e5ef4f88c2f2",
+      "text": "automodterm1",
+      "created_at": "2021-09-29T19:45:37Z",
+      "updated_at": "2021-09-29T19:45:37Z",
+      "expires_at": null
+    }"#;
+    let mut list =
+      BlockedTermList::new("1337".to_owned(), vec![serde_json::from_str(term).unwrap()]);
+
+    let event = r#"
+    {
+      "broadcaster_user_id": "1337",
+      "broadcaster_user_name": "blah",
+      "broadcaster_user_login": "blahblah",
+      "moderator_user_id": "9001",
+      "moderator_user_login": "the_mod",
+      "moderator_user_name": "The_Mod",
+      "action": "add_blocked",
+      "from_automod": true,
+      "terms": ["automodterm1", "automodterm2"]
+    }"#;
+    list.handle_event(&notification("automod.terms.update", event));
+    assert_eq!(list.len(), 2);
+    assert_eq!(list.id("automodterm1"), Some("520e4d4e-0cda-49c7-821e-e5ef4f88c2f2"));
+    assert_eq!(list.id("automodterm2"), None);
+
+    let event = r#"
+    {
+      "broadcaster_user_id": "1337",
+      "broadcaster_user_login": "blah",
+      "broadcaster_user_name": "blahblah",
+      "source_broadcaster_user_id": "1337",
+      "source_broadcaster_user_login": "blah",
+      "source_broadcaster_user_name": "blahblah",
+      "moderator_user_id": "9001",
+      "moderator_user_login": "the_mod",
+      "moderator_user_name": "The_Mod",
+      "action": "remove_blocked_term",
+      "automod_terms": {
+        "action": "remove",
+        "list": "blocked",
+        "terms": ["automodterm1"],
+        "from_automod": false
+      }
+    }"#;
+    list.handle_event(&notification("channel.moderate", event));
+    assert!(!list.contains("automodterm1"));
+    assert!(list.contains("automodterm2"));
+  }
+
+  #[test]
+  fn blocked_term_list_other_channel() {
+    let mut list = BlockedTermList::new("1337".to_owned(), Vec::new());
+
+    let event = r#"
+    {
+      "broadcaster_user_id": "4242",
+      "broadcaster_user_name": "other",
+      "broadcaster_user_login": "Other",
+      "moderator_user_id": "9001",
+      "moderator_user_login": "the_mod",
+      "moderator_user_name": "The_Mod",
+      "action": "add_blocked",
+      "from_automod": false,
+      "terms": ["automodterm1"]
+    }"#;
+    list.handle_event(&notification("automod.terms.update", event));
+    assert!(list.is_empty());
+  }
+}
@@ -0,0 +1,222 @@
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, Method, broadcaster, found_channel};
+use crate::{Twitch, TwitchError, User};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// No scope is required.
+  pub fn get_channel_information(
+    &self,
+    broadcaster_ids: &[&str],
+  ) -> Result<Vec<ChannelInformation>, TwitchError> {
+    let mut channels = Vec::new();
+    for ids in broadcaster_ids.chunks(100) {
+      let query: Vec<_> = ids.iter().map(|&id| ("broadcaster_id", id)).collect();
+      channels.extend(self.api.request(Method::Get, APIEndpoint::Channels, &query).list()?);
+    }
+
+    Ok(channels)
+  }
+
+  /// Returns the first 100 channels matching the query.
+  ///
+  /// No scope is required.
+  pub fn search_channels(
+    &self,
+    search: &str,
+    live_only: bool,
+  ) -> Result<Vec<FoundChannel>, TwitchError> {
+    let live_only = live_only.to_string();
+    let query = [("query", search), ("live_only", &live_only), ("first", "100")];
+
+    Ok(self.api.request(Method::Get, APIEndpoint::SearchChannels, &query).list()?)
+  }
+
+  /// Requires `Scope::ChannelManageBroadcast`.
+  pub fn modify_channel_information(
+    &self,
+    broadcaster_id: &str,
+    changes: &ChannelInformationBuilder,
+  ) -> Result<(), TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id)];
+
+    Ok(self.api.request(Method::Patch, APIEndpoint::Channels, &query).json(changes).empty()?)
+  }
+
+  /// Requires `Scope::ChannelReadEditors`.
+  pub fn get_channel_editors(
+    &self,
+    broadcaster_id: &str,
+  ) -> Result<Vec<ChannelEditor>, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id)];
+
+    Ok(self.api.request(Method::Get, APIEndpoint::ChannelEditors, &query).list()?)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChannelInformation {
+  #[serde(flatten, with = "broadcaster")]
+  pub broadcaster: User,
+  pub broadcaster_language: String,
+  pub game_id: String,
+  pub game_name: String,
+  pub title: String,
+  pub delay: u32,
+  pub tags: Vec<String>,
+  pub content_classification_labels: Vec<String>,
+  pub is_branded_content: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FoundChannel {
+  #[serde(flatten, with = "found_channel")]
+  pub broadcaster:          User,
+  pub broadcaster_language: String,
+  pub game_id:              String,
+  pub game_name:            String,
+  pub title:                String,
+  pub tags:                 Vec<String>,
+  pub thumbnail_url:        String,
+  pub is_live:              bool,
+  pub started_at:           String,
+}
+
+/// Editors are only sent with their id and display name.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChannelEditor {
+  pub user_id:    String,
+  pub user_name:  String,
+  pub created_at: String,
+}
+
+/// Partial update of a channel's information, only the fields that were set are changed.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ChannelInformationBuilder {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  game_id: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  broadcaster_language: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  title: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  delay: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  tags: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  content_classification_labels: Vec<ClassificationLabel>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  is_branded_content: Option<bool>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+struct ClassificationLabel {
+  id:         String,
+  is_enabled: bool,
+}
+
+impl ChannelInformationBuilder {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Use an empty id to unset the category.
+  pub fn game_id(mut self, game_id: impl Into<String>) -> Self {
+    self.game_id = Some(game_id.into());
+    self
+  }
+
+  pub fn broadcaster_language(mut self, language: impl Into<String>) -> Self {
+    self.broadcaster_language = Some(language.into());
+    self
+  }
+
+  pub fn title(mut self, title: impl Into<String>) -> Self {
+    self.title = Some(title.into());
+    self
+  }
+
+  pub fn delay(mut self, delay: u32) -> Self {
+    self.delay = Some(delay);
+    self
+  }
+
+  /// Replaces all tags of the channel, an empty list removes them.
+  pub fn tags<S: Into<String>>(mut self, tags: impl IntoIterator<Item = S>) -> Self {
+    self.tags = Some(tags.into_iter().map(Into::into).collect());
+    self
+  }
+
+  pub fn content_classification_label(mut self, id: impl Into<String>, is_enabled: bool) -> Self {
+    self.content_classification_labels.push(ClassificationLabel { id: id.into(), is_enabled });
+    self
+  }
+
+  pub fn branded_content(mut self, is_branded_content: bool) -> Self {
+    self.is_branded_content = Some(is_branded_content);
+    self
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn get_channel_information() {
+    let response = r#"
+    {
+      "broadcaster_id": "141981764",
+      "broadcaster_login": "twitchdev",
+      "broadcaster_name": "TwitchDev",
+      "broadcaster_language": "en",
+      "game_id": "509670",
+      "game_name": "Science & Technology",
+      "title": "TwitchDev Monthly Update // May 6, 2021",
+      "delay": 0,
+      "tags": ["DevsInTheKnow"],
+      "content_classification_labels": ["Gambling", "DrugsIntoxication", "MatureGame"],
+      "is_branded_content": false
+    }"#;
+    serde_json::from_str::<ChannelInformation>(response).unwrap();
+  }
+
+  #[test]
+  fn search_channels() {
+    let response = r#"
+    {
+      "broadcaster_language": "en",
+      "broadcaster_login": "loserfruit",
+      "display_name": "Loserfruit",
+      "game_id": "498000",
+      "game_name": "House Flipper",
+      "id": "41245072",
+      "is_live": false,
+      "tags": [],
+      "thumbnail_url": "https://static-cdn.jtvnw.net/jtv_user_pictures/fd17325a-7dc2-46c6-8617-e90ec259501c-profile_image-300x300.png",
+      "title": "loserfruit",
+      "started_at": ""
+    }"#;
+    let channel = serde_json::from_str::<FoundChannel>(response).unwrap();
+    assert_eq!(channel.broadcaster.user_id, "41245072");
+  }
+
+  #[test]
+  fn channel_information_builder() {
+    let changes = ChannelInformationBuilder::new()
+      .title("there are helicopters in the game? REASON TO PLAY FORTNITE found")
+      .tags(Vec::<String>::new())
+      .content_classification_label("Gambling", true);
+    let changes = serde_json::to_value(&changes).unwrap();
+    assert_eq!(changes["tags"], serde_json::json!([]));
+    assert_eq!(changes["content_classification_labels"][0]["id"], "Gambling");
+    assert!(changes.get("game_id").is_none());
+    assert!(changes.get("is_branded_content").is_none());
+  }
+}
@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, Method, broadcaster};
+use crate::{DonationAmount, Twitch, TwitchError, User};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Requires `Scope::ChannelReadCharity`, returns `None` if no campaign is running.
+  pub fn get_charity_campaign(
+    &self,
+    broadcaster_id: &str,
+  ) -> Result<Option<CharityCampaign>, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id)];
+    let campaigns = self.api.request(Method::Get, APIEndpoint::CharityCampaigns, &query).list()?;
+
+    Ok(campaigns.into_iter().next())
+  }
+
+  /// Requires `Scope::ChannelReadCharity`.
+  pub fn get_charity_campaign_donations(
+    &self,
+    broadcaster_id: &str,
+  ) -> Result<Vec<CampaignDonation>, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("first", "100")];
+
+    Ok(self.api.get(APIEndpoint::CharityDonations, &query)?)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CharityCampaign {
+  pub id:                  String,
+  #[serde(flatten, with = "broadcaster")]
+  pub broadcaster:         User,
+  pub charity_name:        String,
+  pub charity_description: String,
+  pub charity_logo:        String,
+  pub charity_website:     String,
+  pub current_amount:      DonationAmount,
+  pub target_amount:       Option<DonationAmount>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CampaignDonation {
+  pub id:          String,
+  pub campaign_id: String,
+  #[serde(flatten)]
+  pub user:        User,
+  pub amount:      DonationAmount,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn get_charity_campaign() {
+    let response = r#"
+    {
+      "id": "123-abc-456-def",
+      "broadcaster_id": "123456",
+      "broadcaster_name": "SunnySideUp",
+      "broadcaster_login": "sunnysideup",
+      "charity_name": "Example name",
+      "charity_description": "Example description",
+      "charity_logo": "https://abc.cloudfront.net/ppgf/1000/100.png",
+      "charity_website": "https://www.example.com",
+      "current_amount": {
+        "value": 86000,
+        "decimal_places": 2,
+        "currency": "USD"
+      },
+      "target_amount": {
+        "value": 1500000,
+        "decimal_places": 2,
+        "currency": "USD"
+      }
+    }"#;
+    serde_json::from_str::<CharityCampaign>(response).unwrap();
+  }
+
+  #[test]
+  fn get_charity_campaign_donations() {
+    let response = r#"
+    {
+      "id": "a1b2c3-aabb-4455-d1e2f3",
+      "campaign_id": "123-abc-456-def",
+      "user_id": "5678",
+      "user_login": "cool_user",
+      "user_name": "Cool_User",
+      "amount": {
+        "value": 500,
+        "decimal_places": 2,
+        "currency": "USD"
+      }
+    }"#;
+    serde_json::from_str::<CampaignDonation>(response).unwrap();
+  }
+}
@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, Method};
+use crate::{Twitch, TwitchError};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Requires `Scope::ClipsEdit`. The clip is processed asynchronously, check for it with
+  /// [`Twitch::get_clips`] after a few seconds.
+  pub fn create_clip(&self, broadcaster_id: &str, has_delay: bool) -> Result<NewClip, TwitchError> {
+    let has_delay = has_delay.to_string();
+    let query = [("broadcaster_id", broadcaster_id), ("has_delay", &has_delay)];
+
+    Ok(self.api.request(Method::Post, APIEndpoint::Clips, &query).one()?)
+  }
+
+  /// Clips of a broadcaster or game are fetched up to [`ClipFilter::max_results`].
+  ///
+  /// No scope is required.
+  pub fn get_clips(&self, filter: &ClipFilter) -> Result<Vec<Clip>, TwitchError> {
+    let mut query: Vec<_> =
+      filter.source.iter().map(|(key, value)| (*key, value.as_str())).collect();
+    query.extend(filter.started_at.as_deref().map(|time| ("started_at", time)));
+    query.extend(filter.ended_at.as_deref().map(|time| ("ended_at", time)));
+    query.extend(filter.is_featured.as_deref().map(|featured| ("is_featured", featured)));
+
+    if filter.source.iter().any(|(key, _)| *key == "id") {
+      Ok(self.api.request(Method::Get, APIEndpoint::Clips, &query).list()?)
+    } else {
+      let max_results = filter.max_results.unwrap_or(DEFAULT_MAX_CLIPS);
+      Ok(self.api.get_limited(APIEndpoint::Clips, &query, max_results)?)
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const DEFAULT_MAX_CLIPS: usize = 100;
+
+/// Selects clips of a broadcaster, of a game or by id, optionally limited to a date range.
+#[derive(Debug, Clone)]
+pub struct ClipFilter {
+  source:      Vec<(&'static str, String)>,
+  started_at:  Option<String>,
+  ended_at:    Option<String>,
+  is_featured: Option<String>,
+  max_results: Option<usize>,
+}
+
+impl ClipFilter {
+  pub fn broadcaster(broadcaster_id: impl Into<String>) -> Self {
+    Self::from_source(vec![("broadcaster_id", broadcaster_id.into())])
+  }
+
+  pub fn game(game_id: impl Into<String>) -> Self {
+    Self::from_source(vec![("game_id", game_id.into())])
+  }
+
+  /// At most 100 clips may be requested by id.
+  pub fn ids<S: Into<String>>(clip_ids: impl IntoIterator<Item = S>) -> Self {
+    Self::from_source(clip_ids.into_iter().map(|id| ("id", id.into())).collect())
+  }
+
+  pub fn started_at(mut self, started_at: impl Into<String>) -> Self {
+    self.started_at = Some(started_at.into());
+    self
+  }
+
+  pub fn ended_at(mut self, ended_at: impl Into<String>) -> Self {
+    self.ended_at = Some(ended_at.into());
+    self
+  }
+
+  pub fn featured(mut self, is_featured: bool) -> Self {
+    self.is_featured = Some(is_featured.to_string());
+    self
+  }
+
+  /// Stops fetching clips of a broadcaster or game after this many, defaults to 100.
+  pub fn max_results(mut self, max_results: usize) -> Self {
+    self.max_results = Some(max_results);
+    self
+  }
+
+  fn from_source(source: Vec<(&'static str, String)>) -> Self {
+    Self { source, started_at: None, ended_at: None, is_featured: None, max_results: None }
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NewClip {
+  pub id:       String,
+  pub edit_url: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Clip {
+  pub id:               String,
+  pub url:              String,
+  pub embed_url:        String,
+  pub broadcaster_id:   String,
+  pub broadcaster_name: String,
+  pub creator_id:       String,
+  pub creator_name:     String,
+  pub video_id:         String,
+  pub game_id:          String,
+  pub language:         String,
+  pub title:            String,
+  pub view_count:       u64,
+  pub created_at:       String,
+  pub thumbnail_url:    String,
+  pub duration:         f64,
+  pub vod_offset:       Option<u64>,
+  pub is_featured:      bool,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn create_clip() {
+    let response = r#"
+    {
+      "id": "FiveWordsForClipSlug",
+      "edit_url": "http://clips.twitch.tv/FiveWordsForClipSlug/edit"
+    }"#;
+    serde_json::from_str::<NewClip>(response).unwrap();
+  }
+
+  #[test]
+  fn get_clips() {
+    let response = r#"
+    {
+      "id": "RandomClip1",
+      "url": "https://clips.twitch.tv/AwkwardHelplessSalamanderSwiftRage",
+      "embed_url": "https://clips.twitch.tv/embed?clip=RandomClip1",
+      "broadcaster_id": "1234",
+      "broadcaster_name": "JJ",
+      "creator_id": "123456",
+      "creator_name": "MrMarshall",
+      "video_id": "",
+      "game_id": "33103",
+      "language": "en",
+      "title": "random1",
+      "view_count": 10,
+      "created_at": "2017-11-30T22:34:18Z",
+      "thumbnail_url": "https://clips-media-assets.twitch.tv/157589949-preview-480x272.jpg",
+      "duration": 12.9,
+      "vod_offset": 1957,
+      "is_featured": true
+    }"#;
+    serde_json::from_str::<Clip>(response).unwrap();
+  }
+}
@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, APIError, broadcaster};
+use crate::{Event, Twitch, TwitchError, User};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Requires `Scope::ModeratorReadFollowers`.
+  pub fn get_channel_followers(
+    &self,
+    broadcaster_id: &str,
+  ) -> Result<ChannelFollowers, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("first", "100")];
+
+    let (followers, totals) =
+      self.api.get_with_totals::<_, FollowTotals>(APIEndpoint::ChannelFollowers, &query)?;
+
+    Ok(ChannelFollowers { followers, total: totals.total })
+  }
+
+  /// Only requests the first follower, to get the follower count without paging through them.
+  ///
+  /// No scope is required.
+  pub fn get_follower_count(&self, broadcaster_id: &str) -> Result<u64, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("first", "1")];
+
+    let (_, totals) = self
+      .api
+      .get_list_with_totals::<Follower, FollowTotals>(APIEndpoint::ChannelFollowers, &query)?;
+
+    Ok(totals.total)
+  }
+
+  /// Requires `Scope::ModeratorReadFollowers`, returns `None` if the user doesn't follow the
+  /// channel.
+  pub fn check_follower(
+    &self,
+    broadcaster_id: &str,
+    user_id: &str,
+  ) -> Result<Option<Follower>, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("user_id", user_id)];
+    let (followers, totals) = self
+      .api
+      .get_list_with_totals::<Follower, FollowTotals>(APIEndpoint::ChannelFollowers, &query)?;
+
+    // Without the scope twitch answers with the follower count of the channel but no followers,
+    // which would be indistinguishable from the user not following.
+    if followers.is_empty() && totals.total > 0 {
+      return Err(APIError::Forbidden.into());
+    }
+
+    Ok(followers.into_iter().next())
+  }
+
+  /// Requires `Scope::UserReadFollows`.
+  pub fn get_followed_channels(&self, user_id: &str) -> Result<Vec<FollowedChannel>, TwitchError> {
+    let query = [("user_id", user_id), ("first", "100")];
+
+    Ok(self.api.get(APIEndpoint::FollowedChannels, &query)?)
+  }
+
+  /// No scope is required.
+  pub fn get_follower_counter(&self, broadcaster_id: &str) -> Result<FollowerCounter, TwitchError> {
+    let total = self.get_follower_count(broadcaster_id)?;
+
+    Ok(FollowerCounter::new(broadcaster_id.to_owned(), total))
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Deserialize, Debug)]
+struct FollowTotals {
+  total: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChannelFollowers {
+  pub followers: Vec<Follower>,
+  pub total:     u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Follower {
+  #[serde(flatten)]
+  pub user:        User,
+  pub followed_at: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FollowedChannel {
+  #[serde(flatten, with = "broadcaster")]
+  pub broadcaster: User,
+  pub followed_at: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Follower count of a single channel, kept up to date by feeding it eventsub events.
+///
+/// Eventsub has no unfollow event, so the count drifts upwards until it is reset from
+/// [`Twitch::get_follower_count`].
+#[derive(Debug, Clone)]
+pub struct FollowerCounter {
+  broadcaster_id: String,
+  count:          u64,
+}
+
+impl FollowerCounter {
+  pub fn new(broadcaster_id: String, count: u64) -> Self {
+    Self { broadcaster_id, count }
+  }
+
+  pub fn broadcaster_id(&self) -> &str {
+    &self.broadcaster_id
+  }
+
+  pub fn count(&self) -> u64 {
+    self.count
+  }
+
+  pub fn reset(&mut self, count: u64) {
+    self.count = count;
+  }
+
+  pub fn handle_event(&mut self, event: &Event) {
+    if let Event::Follow(follow) = event
+      && follow.broadcaster.user_id == self.broadcaster_id
+    {
+      self.count += 1;
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::eventsub::events::notification;
+
+  #[test]
+  fn get_channel_followers() {
+    let response = r#"
+    {
+      "user_id": "11111",
+      "user_name": "UserDisplayName",
+      "user_login": "userloginname",
+      "followed_at": "2022-05-24T22:22:08Z"
+    }"#;
+    serde_json::from_str::<Follower>(response).unwrap();
+  }
+
+  #[test]
+  fn get_followed_channels() {
+    let response = r#"
+    {
+      "broadcaster_id": "654321",
+      "broadcaster_login": "basketweaver101",
+      "broadcaster_name": "BasketWeaver101",
+      "followed_at": "2022-05-24T22:22:08Z"
+    }"#;
+    serde_json::from_str::<FollowedChannel>(response).unwrap();
+  }
+
+  #[test]
+  fn follower_counter() {
+    let mut counter = FollowerCounter::new("1337".to_owned(), 41);
+
+    let event = r#"
+    {
+      "user_id": "1234",
+      "user_login": "cool_user",
+      "user_name": "Cool_User",
+      "broadcaster_user_id": "1337",
+      "broadcaster_user_login": "cooler_user",
+      "broadcaster_user_name": "Cooler_User",
+      "followed_at": "2020-07-15T18:16:11.17106713Z"
+    }"#;
+    counter.handle_event(&notification("channel.follow", event));
+    assert_eq!(counter.count(), 42);
+
+    counter.handle_event(&notification("channel.follow", &event.replace("1337", "4242")));
+    assert_eq!(counter.count(), 42);
+  }
+}
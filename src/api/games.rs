@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, Method};
+use crate::{Twitch, TwitchError};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Looks up games by id and exact name, splitting the lookup into requests of at most 100 games.
+  ///
+  /// No scope is required.
+  pub fn get_games(&self, game_ids: &[&str], names: &[&str]) -> Result<Vec<Game>, TwitchError> {
+    let lookups: Vec<_> = game_ids
+      .iter()
+      .map(|&id| ("id", id))
+      .chain(names.iter().map(|&name| ("name", name)))
+      .collect();
+
+    let mut games = Vec::new();
+    for query in lookups.chunks(100) {
+      games.extend(self.api.request(Method::Get, APIEndpoint::Games, query).list()?);
+    }
+
+    Ok(games)
+  }
+
+  /// No scope is required.
+  pub fn get_game_by_name(&self, name: &str) -> Result<Option<Game>, TwitchError> {
+    Ok(self.get_games(&[], &[name])?.into_iter().next())
+  }
+
+  /// Returns the first 100 categories matching the query.
+  ///
+  /// No scope is required.
+  pub fn search_categories(&self, search: &str) -> Result<Vec<Game>, TwitchError> {
+    let query = [("query", search), ("first", "100")];
+
+    Ok(self.api.request(Method::Get, APIEndpoint::SearchCategories, &query).list()?)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Game {
+  pub id:          String,
+  pub name:        String,
+  pub box_art_url: String,
+  pub igdb_id:     Option<String>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn get_games() {
+    let response = r#"
+    {
+      "id": "33214",
+      "name": "Fortnite",
+      "box_art_url": "https://static-cdn.jtvnw.net/ttv-boxart/33214-{width}x{height}.jpg",
+      "igdb_id": "1905"
+    }"#;
+    serde_json::from_str::<Game>(response).unwrap();
+
+    let response = r#"
+    {
+      "box_art_url": "https://static-cdn.jtvnw.net/ttv-boxart/33214-52x72.jpg",
+      "name": "Fortnite",
+      "id": "33214"
+    }"#;
+    serde_json::from_str::<Game>(response).unwrap();
+  }
+}
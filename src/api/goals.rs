@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, Method, broadcaster};
+use crate::{GoalType, Twitch, TwitchError, User};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Requires `Scope::ChannelReadGoals`.
+  pub fn get_creator_goals(&self, broadcaster_id: &str) -> Result<Vec<CreatorGoal>, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id)];
+
+    Ok(self.api.request(Method::Get, APIEndpoint::Goals, &query).list()?)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreatorGoal {
+  pub id:             String,
+  #[serde(flatten, with = "broadcaster")]
+  pub broadcaster:    User,
+  #[serde(rename = "type")]
+  pub goal_type:      GoalType,
+  pub description:    String,
+  pub current_amount: u64,
+  pub target_amount:  u64,
+  pub created_at:     String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn get_creator_goals() {
+    let response = r#"
+    {
+      "id": "1woowvbkiNv8BRxEWSqmQz6Zk92",
+      "broadcaster_id": "141981764",
+      "broadcaster_name": "TwitchDev",
+      "broadcaster_login": "twitchdev",
+      "type": "follower",
+      "description": "Follow goal for Helix testing",
+      "current_amount": 27062,
+      "target_amount": 30000,
+      "created_at": "2021-08-16T17:22:23Z"
+    }"#;
+    let goal = serde_json::from_str::<CreatorGoal>(response).unwrap();
+    assert!(matches!(goal.goal_type, GoalType::Follow));
+  }
+}
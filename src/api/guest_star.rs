@@ -0,0 +1,435 @@
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, Method, guest};
+use crate::{Twitch, TwitchError, User};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Requires `Scope::ChannelReadGuestStar` or `Scope::ModeratorReadGuestStar`.
+  pub fn get_channel_guest_star_settings(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+  ) -> Result<GuestStarSettings, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("moderator_id", moderator_id)];
+
+    Ok(self.api.request(Method::Get, APIEndpoint::GuestStarSettings, &query).one()?)
+  }
+
+  /// Requires `Scope::ChannelManageGuestStar`.
+  pub fn update_channel_guest_star_settings(
+    &self,
+    broadcaster_id: &str,
+    settings: &GuestStarSettingsBuilder,
+  ) -> Result<(), TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id)];
+
+    Ok(
+      self
+        .api
+        .request(Method::Put, APIEndpoint::GuestStarSettings, &query)
+        .json(settings)
+        .empty()?,
+    )
+  }
+
+  /// Returns `None` if the broadcaster has no active session.
+  ///
+  /// Requires `Scope::ChannelReadGuestStar`, `Scope::ChannelManageGuestStar`,
+  /// `Scope::ModeratorReadGuestStar` or `Scope::ModeratorManageGuestStar`.
+  pub fn get_guest_star_session(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+  ) -> Result<Option<GuestStarSession>, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("moderator_id", moderator_id)];
+
+    Ok(self.api.request(Method::Get, APIEndpoint::GuestStarSession, &query).list()?.pop())
+  }
+
+  /// Requires `Scope::ChannelManageGuestStar`.
+  pub fn create_guest_star_session(
+    &self,
+    broadcaster_id: &str,
+  ) -> Result<GuestStarSession, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id)];
+
+    Ok(self.api.request(Method::Post, APIEndpoint::GuestStarSession, &query).one()?)
+  }
+
+  /// Requires `Scope::ChannelManageGuestStar`.
+  pub fn end_guest_star_session(
+    &self,
+    broadcaster_id: &str,
+    session_id: &str,
+  ) -> Result<GuestStarSession, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("session_id", session_id)];
+
+    Ok(self.api.request(Method::Delete, APIEndpoint::GuestStarSession, &query).one()?)
+  }
+
+  /// Requires `Scope::ChannelReadGuestStar`, `Scope::ChannelManageGuestStar`,
+  /// `Scope::ModeratorReadGuestStar` or `Scope::ModeratorManageGuestStar`.
+  pub fn get_guest_star_invites(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    session_id: &str,
+  ) -> Result<Vec<GuestStarInvite>, TwitchError> {
+    let query = [
+      ("broadcaster_id", broadcaster_id),
+      ("moderator_id", moderator_id),
+      ("session_id", session_id),
+    ];
+
+    Ok(self.api.request(Method::Get, APIEndpoint::GuestStarInvites, &query).list()?)
+  }
+
+  /// Requires `Scope::ChannelManageGuestStar` or `Scope::ModeratorManageGuestStar`.
+  pub fn send_guest_star_invite(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    session_id: &str,
+    guest_id: &str,
+  ) -> Result<(), TwitchError> {
+    let query = [
+      ("broadcaster_id", broadcaster_id),
+      ("moderator_id", moderator_id),
+      ("session_id", session_id),
+      ("guest_id", guest_id),
+    ];
+
+    Ok(self.api.request(Method::Post, APIEndpoint::GuestStarInvites, &query).empty()?)
+  }
+
+  /// Requires `Scope::ChannelManageGuestStar` or `Scope::ModeratorManageGuestStar`.
+  pub fn delete_guest_star_invite(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    session_id: &str,
+    guest_id: &str,
+  ) -> Result<(), TwitchError> {
+    let query = [
+      ("broadcaster_id", broadcaster_id),
+      ("moderator_id", moderator_id),
+      ("session_id", session_id),
+      ("guest_id", guest_id),
+    ];
+
+    Ok(self.api.request(Method::Delete, APIEndpoint::GuestStarInvites, &query).empty()?)
+  }
+
+  /// Moves an invited guest into a slot, the guest has to be ready before they can be assigned.
+  ///
+  /// Requires `Scope::ChannelManageGuestStar` or `Scope::ModeratorManageGuestStar`.
+  pub fn assign_guest_star_slot(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    session_id: &str,
+    guest_id: &str,
+    slot_id: &str,
+  ) -> Result<(), TwitchError> {
+    let query = [
+      ("broadcaster_id", broadcaster_id),
+      ("moderator_id", moderator_id),
+      ("session_id", session_id),
+      ("guest_id", guest_id),
+      ("slot_id", slot_id),
+    ];
+
+    Ok(self.api.request(Method::Post, APIEndpoint::GuestStarSlot, &query).empty()?)
+  }
+
+  /// Moves the guest in `source_slot_id` to another slot, swapping guests if the destination is
+  /// occupied. Without a destination the guest is moved to the first open slot.
+  ///
+  /// Requires `Scope::ChannelManageGuestStar` or `Scope::ModeratorManageGuestStar`.
+  pub fn update_guest_star_slot(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    session_id: &str,
+    source_slot_id: &str,
+    destination_slot_id: Option<&str>,
+  ) -> Result<(), TwitchError> {
+    let mut query = vec![
+      ("broadcaster_id", broadcaster_id),
+      ("moderator_id", moderator_id),
+      ("session_id", session_id),
+      ("source_slot_id", source_slot_id),
+    ];
+    query.extend(destination_slot_id.map(|id| ("destination_slot_id", id)));
+
+    Ok(self.api.request(Method::Patch, APIEndpoint::GuestStarSlot, &query).empty()?)
+  }
+
+  /// Requires `Scope::ChannelManageGuestStar` or `Scope::ModeratorManageGuestStar`.
+  pub fn delete_guest_star_slot(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    session_id: &str,
+    guest_id: &str,
+    slot_id: &str,
+    reinvite_guest: bool,
+  ) -> Result<(), TwitchError> {
+    let reinvite_guest = reinvite_guest.to_string();
+    let query = [
+      ("broadcaster_id", broadcaster_id),
+      ("moderator_id", moderator_id),
+      ("session_id", session_id),
+      ("guest_id", guest_id),
+      ("slot_id", slot_id),
+      ("should_reinvite_guest", &reinvite_guest),
+    ];
+
+    Ok(self.api.request(Method::Delete, APIEndpoint::GuestStarSlot, &query).empty()?)
+  }
+
+  /// Requires `Scope::ChannelManageGuestStar` or `Scope::ModeratorManageGuestStar`.
+  pub fn update_guest_star_slot_settings(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    session_id: &str,
+    slot_id: &str,
+    settings: &SlotSettings,
+  ) -> Result<(), TwitchError> {
+    let mut query = vec![
+      ("broadcaster_id", broadcaster_id),
+      ("moderator_id", moderator_id),
+      ("session_id", session_id),
+      ("slot_id", slot_id),
+    ];
+    query.extend(settings.source.iter().map(|(key, value)| (*key, value.as_str())));
+
+    Ok(self.api.request(Method::Patch, APIEndpoint::GuestStarSlotSettings, &query).empty()?)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GuestStarSettings {
+  pub is_moderator_send_live_enabled: bool,
+  pub slot_count: u32,
+  pub is_browser_source_audio_enabled: bool,
+  pub group_layout: GroupLayout,
+  pub browser_source_token: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum GroupLayout {
+  TiledLayout,
+  ScreenshareLayout,
+  HorizontalLayout,
+  VerticalLayout,
+}
+
+/// Guest Star settings to change, only the fields that were set are sent.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct GuestStarSettingsBuilder {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  is_moderator_send_live_enabled: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  slot_count: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  is_browser_source_audio_enabled: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  group_layout: Option<GroupLayout>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  regenerate_browser_sources: Option<bool>,
+}
+
+impl GuestStarSettingsBuilder {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn moderator_send_live(mut self, enabled: bool) -> Self {
+    self.is_moderator_send_live_enabled = Some(enabled);
+    self
+  }
+
+  /// Between 1 and 6 slots.
+  pub fn slot_count(mut self, slot_count: u32) -> Self {
+    self.slot_count = Some(slot_count);
+    self
+  }
+
+  pub fn browser_source_audio(mut self, enabled: bool) -> Self {
+    self.is_browser_source_audio_enabled = Some(enabled);
+    self
+  }
+
+  pub fn group_layout(mut self, group_layout: GroupLayout) -> Self {
+    self.group_layout = Some(group_layout);
+    self
+  }
+
+  /// Invalidates all existing browser source links.
+  pub fn regenerate_browser_sources(mut self) -> Self {
+    self.regenerate_browser_sources = Some(true);
+    self
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GuestStarSession {
+  pub id:     String,
+  pub guests: Vec<Guest>,
+}
+
+/// The host is always in slot `0`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Guest {
+  pub slot_id:        String,
+  pub is_live:        bool,
+  #[serde(flatten, with = "guest")]
+  pub user:           User,
+  pub volume:         u32,
+  pub assigned_at:    String,
+  pub audio_settings: MediaSettings,
+  pub video_settings: MediaSettings,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MediaSettings {
+  pub is_host_enabled:  bool,
+  pub is_guest_enabled: bool,
+  pub is_available:     bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GuestStarInvite {
+  pub user_id:            String,
+  pub invited_at:         String,
+  pub status:             InviteStatus,
+  pub is_audio_enabled:   bool,
+  pub is_video_enabled:   bool,
+  pub is_audio_available: bool,
+  pub is_video_available: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum InviteStatus {
+  Invited,
+  Accepted,
+  Ready,
+}
+
+/// Media settings of a slot to change, only the settings that were set are sent.
+#[derive(Debug, Clone, Default)]
+pub struct SlotSettings {
+  source: Vec<(&'static str, String)>,
+}
+
+impl SlotSettings {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn audio_enabled(mut self, enabled: bool) -> Self {
+    self.source.push(("is_audio_enabled", enabled.to_string()));
+    self
+  }
+
+  pub fn video_enabled(mut self, enabled: bool) -> Self {
+    self.source.push(("is_video_enabled", enabled.to_string()));
+    self
+  }
+
+  pub fn live(mut self, is_live: bool) -> Self {
+    self.source.push(("is_live", is_live.to_string()));
+    self
+  }
+
+  /// Between 0 and 100.
+  pub fn volume(mut self, volume: u32) -> Self {
+    self.source.push(("volume", volume.to_string()));
+    self
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn get_channel_guest_star_settings() {
+    let response = r#"
+    {
+      "is_moderator_send_live_enabled": true,
+      "slot_count": 4,
+      "is_browser_source_audio_enabled": true,
+      "group_layout": "TILED_LAYOUT",
+      "browser_source_token": "eihq8rew7q3hgierufhi3q"
+    }"#;
+    let settings = serde_json::from_str::<GuestStarSettings>(response).unwrap();
+    assert_eq!(settings.group_layout, GroupLayout::TiledLayout);
+  }
+
+  #[test]
+  fn get_guest_star_session() {
+    let response = r#"
+    {
+      "id": "2KFRQbFtpmfyD3IevNRnCzOPRJI",
+      "guests": [
+        {
+          "slot_id": "0",
+          "id": "238b4b08-13f1-4b8f-8d31-56665a7a9d9f",
+          "user_id": "9321049",
+          "user_display_name": "Cool_User",
+          "user_login": "cool_user",
+          "is_live": true,
+          "volume": 100,
+          "assigned_at": "2023-01-02T04:16:53.325Z",
+          "audio_settings": {
+            "is_available": true,
+            "is_host_enabled": true,
+            "is_guest_enabled": true
+          },
+          "video_settings": {
+            "is_available": true,
+            "is_host_enabled": true,
+            "is_guest_enabled": true
+          }
+        }
+      ]
+    }"#;
+    let session = serde_json::from_str::<GuestStarSession>(response).unwrap();
+    assert_eq!(session.guests[0].user.user_name, "Cool_User");
+  }
+
+  #[test]
+  fn get_guest_star_invites() {
+    let response = r#"
+    {
+      "user_id": "144601104",
+      "invited_at": "2023-07-26T18:06:46.452Z",
+      "status": "INVITED",
+      "is_audio_enabled": false,
+      "is_video_enabled": true,
+      "is_audio_available": true,
+      "is_video_available": true
+    }"#;
+    let invite = serde_json::from_str::<GuestStarInvite>(response).unwrap();
+    assert_eq!(invite.status, InviteStatus::Invited);
+  }
+
+  #[test]
+  fn update_channel_guest_star_settings() {
+    let settings =
+      GuestStarSettingsBuilder::new().slot_count(4).group_layout(GroupLayout::TiledLayout);
+    let request = serde_json::to_string(&settings).unwrap();
+    assert_eq!(request, r#"{"slot_count":4,"group_layout":"TILED_LAYOUT"}"#);
+  }
+}
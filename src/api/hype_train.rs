@@ -0,0 +1,162 @@
+use serde::{Deserialize, Serialize};
+
+use super::APIEndpoint;
+use crate::{Contribution, Twitch, TwitchError};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Requires `Scope::ChannelReadHypeTrain`. Returns the events of the most recent hype
+  /// train, newest first.
+  pub fn get_hype_train_events(
+    &self,
+    broadcaster_id: &str,
+  ) -> Result<Vec<HypeTrainEvent>, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("first", "100")];
+
+    Ok(self.api.get(APIEndpoint::HypeTrainEvents, &query)?)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HypeTrainEvent {
+  pub id:              String,
+  pub event_type:      String,
+  pub event_timestamp: String,
+  pub version:         String,
+  pub event_data:      HypeTrainData,
+}
+
+/// Contributions reuse the eventsub [`Contribution`], but helix only identifies contributors by
+/// id, so `user_login` and `user_name` of their users are always empty.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HypeTrainData {
+  pub id:                String,
+  pub broadcaster_id:    String,
+  pub level:             u64,
+  pub total:             u64,
+  pub goal:              u64,
+  #[serde(with = "helix_contribution::list")]
+  pub top_contributions: Vec<Contribution>,
+  #[serde(with = "helix_contribution")]
+  pub last_contribution: Contribution,
+  pub started_at:        String,
+  pub expires_at:        String,
+  pub cooldown_end_time: String,
+}
+
+// Helix hype train events only identify contributors by id, so login and name are left empty.
+mod helix_contribution {
+  use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+  use crate::{Contribution, ContributionType, User};
+
+  #[derive(Serialize, Deserialize)]
+  struct HelixContribution {
+    user:              String,
+    #[serde(rename = "type")]
+    contribution_type: ContributionType,
+    total:             u64,
+  }
+
+  impl HelixContribution {
+    fn new(contribution: &Contribution) -> Self {
+      Self {
+        user:              contribution.user.user_id.clone(),
+        contribution_type: contribution.contribution_type.clone(),
+        total:             contribution.total,
+      }
+    }
+
+    fn into_contribution(self) -> Contribution {
+      Contribution {
+        user:              User {
+          user_id:    self.user,
+          user_login: String::new(),
+          user_name:  String::new(),
+        },
+        contribution_type: self.contribution_type,
+        total:             self.total,
+      }
+    }
+  }
+
+  pub(crate) fn serialize<S: Serializer>(
+    contribution: &Contribution,
+    serializer: S,
+  ) -> Result<S::Ok, S::Error> {
+    HelixContribution::new(contribution).serialize(serializer)
+  }
+
+  pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+  ) -> Result<Contribution, D::Error> {
+    Ok(HelixContribution::deserialize(deserializer)?.into_contribution())
+  }
+
+  pub(crate) mod list {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+      contributions: &[Contribution],
+      serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+      serializer.collect_seq(contributions.iter().map(HelixContribution::new))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+      deserializer: D,
+    ) -> Result<Vec<Contribution>, D::Error> {
+      let contributions = Vec::<HelixContribution>::deserialize(deserializer)?;
+      Ok(contributions.into_iter().map(HelixContribution::into_contribution).collect())
+    }
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::ContributionType;
+
+  #[test]
+  fn get_hype_train_events() {
+    let response = r#"
+    {
+      "id": "1b0AsbInCHZW2SQFQkCzqN07Ib2",
+      "event_type": "hypetrain.progression",
+      "event_timestamp": "2020-04-24T20:07:24Z",
+      "version": "1.0",
+      "event_data": {
+        "broadcaster_id": "270954519",
+        "cooldown_end_time": "2020-04-24T20:13:21.003802269Z",
+        "expires_at": "2020-04-24T20:12:21.003802269Z",
+        "goal": 1800,
+        "id": "70f0c7d8-ff60-4c50-b138-f3a352833b50",
+        "last_contribution": {
+          "total": 200,
+          "type": "BITS",
+          "user": "134247454"
+        },
+        "level": 2,
+        "started_at": "2020-04-24T20:05:47.30473127Z",
+        "top_contributions": [
+          {
+            "total": 600,
+            "type": "BITS",
+            "user": "134247450"
+          }
+        ],
+        "total": 600
+      }
+    }"#;
+    let event = serde_json::from_str::<HypeTrainEvent>(response).unwrap();
+    let contribution = event.event_data.last_contribution;
+    assert!(matches!(contribution.contribution_type, ContributionType::Bits));
+    assert_eq!(contribution.user.user_id, "134247454");
+    assert!(contribution.user.user_login.is_empty());
+  }
+}
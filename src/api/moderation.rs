@@ -0,0 +1,482 @@
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, APIError, Method, broadcaster, moderator, query_value};
+use crate::{TrustStatus, Twitch, TwitchError, UnbanStatus, User};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Requires `Scope::ModeratorManageBannedUsers`.
+  pub fn ban_user(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    user_id: &str,
+    reason: Option<&str>,
+  ) -> Result<BanResult, TwitchError> {
+    self.ban(broadcaster_id, moderator_id, BanData { user_id, duration: None, reason })
+  }
+
+  /// Requires `Scope::ModeratorManageBannedUsers`.
+  pub fn timeout_user(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    user_id: &str,
+    duration: u32,
+    reason: Option<&str>,
+  ) -> Result<BanResult, TwitchError> {
+    self.ban(broadcaster_id, moderator_id, BanData { user_id, duration: Some(duration), reason })
+  }
+
+  /// Requires `Scope::ModeratorManageBannedUsers`.
+  pub fn unban_user(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    user_id: &str,
+  ) -> Result<(), TwitchError> {
+    let query =
+      [("broadcaster_id", broadcaster_id), ("moderator_id", moderator_id), ("user_id", user_id)];
+
+    Ok(self.api.request(Method::Delete, APIEndpoint::Bans, &query).empty().map_err(ban_error)?)
+  }
+
+  /// Requires `Scope::ModeratorManageChatMessages`.
+  pub fn delete_chat_message(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    message_id: &str,
+  ) -> Result<(), TwitchError> {
+    let query = [
+      ("broadcaster_id", broadcaster_id),
+      ("moderator_id", moderator_id),
+      ("message_id", message_id),
+    ];
+
+    Ok(self.api.request(Method::Delete, APIEndpoint::ChatMessages, &query).empty()?)
+  }
+
+  /// Requires `Scope::ModeratorManageChatMessages`.
+  pub fn clear_chat(&self, broadcaster_id: &str, moderator_id: &str) -> Result<(), TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("moderator_id", moderator_id)];
+
+    Ok(self.api.request(Method::Delete, APIEndpoint::ChatMessages, &query).empty()?)
+  }
+
+  /// Requires `Scope::ModerationRead` or `Scope::ModeratorManageBannedUsers`.
+  pub fn get_banned_users(
+    &self,
+    broadcaster_id: &str,
+    user_ids: &[&str],
+  ) -> Result<Vec<BannedUser>, TwitchError> {
+    let mut query = vec![("broadcaster_id", broadcaster_id), ("first", "100")];
+    query.extend(user_ids.iter().map(|&id| ("user_id", id)));
+
+    Ok(self.api.get(APIEndpoint::BannedUsers, &query)?)
+  }
+
+  /// Requires `Scope::ModeratorReadShieldMode`.
+  pub fn get_shield_mode_status(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+  ) -> Result<ShieldModeStatus, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("moderator_id", moderator_id)];
+
+    Ok(self.api.request(Method::Get, APIEndpoint::ShieldMode, &query).one()?)
+  }
+
+  /// Requires `Scope::ModeratorManageShieldMode`.
+  pub fn update_shield_mode_status(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    is_active: bool,
+  ) -> Result<ShieldModeStatus, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("moderator_id", moderator_id)];
+
+    Ok(
+      self
+        .api
+        .request(Method::Put, APIEndpoint::ShieldMode, &query)
+        .json(ShieldModeRequest { is_active })
+        .one()?,
+    )
+  }
+
+  /// Requires `Scope::ModeratorManageWarnings`.
+  pub fn warn_chat_user(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    user_id: &str,
+    reason: &str,
+  ) -> Result<Warning, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("moderator_id", moderator_id)];
+    let request = WarnRequest { data: WarnData { user_id, reason } };
+
+    Ok(self.api.request(Method::Post, APIEndpoint::Warnings, &query).json(request).one()?)
+  }
+
+  /// Requires `Scope::ModeratorManageSuspiciousUsers`.
+  pub fn add_suspicious_status(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    user_id: &str,
+    status: SuspiciousStatus,
+  ) -> Result<SuspiciousUser, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("moderator_id", moderator_id)];
+    let request = SuspiciousStatusRequest { user_id, status };
+
+    Ok(self.api.request(Method::Post, APIEndpoint::SuspiciousUsers, &query).json(request).one()?)
+  }
+
+  /// Requires `Scope::ModeratorManageSuspiciousUsers`.
+  pub fn remove_suspicious_status(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    user_id: &str,
+  ) -> Result<(), TwitchError> {
+    let query =
+      [("broadcaster_id", broadcaster_id), ("moderator_id", moderator_id), ("user_id", user_id)];
+
+    Ok(self.api.request(Method::Delete, APIEndpoint::SuspiciousUsers, &query).empty()?)
+  }
+
+  /// Requires `Scope::ModeratorReadUnbanRequests`.
+  pub fn get_unban_requests(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    status: UnbanStatus,
+    user_id: Option<&str>,
+  ) -> Result<Vec<UnbanRequest>, TwitchError> {
+    let status = query_value(&status);
+    let mut query = vec![
+      ("broadcaster_id", broadcaster_id),
+      ("moderator_id", moderator_id),
+      ("status", &status),
+      ("first", "100"),
+    ];
+    query.extend(user_id.map(|id| ("user_id", id)));
+
+    Ok(self.api.get(APIEndpoint::UnbanRequests, &query)?)
+  }
+
+  /// Requires `Scope::ModeratorManageUnbanRequests`.
+  pub fn resolve_unban_request(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    unban_request_id: &str,
+    status: UnbanRequestResolution,
+    resolution_text: Option<&str>,
+  ) -> Result<UnbanRequest, TwitchError> {
+    let status = query_value(&status);
+    let mut query = vec![
+      ("broadcaster_id", broadcaster_id),
+      ("moderator_id", moderator_id),
+      ("unban_request_id", unban_request_id),
+      ("status", &status),
+    ];
+    query.extend(resolution_text.map(|text| ("resolution_text", text)));
+
+    Ok(self.api.request(Method::Patch, APIEndpoint::UnbanRequests, &query).one()?)
+  }
+
+  fn ban(
+    &self,
+    broadcaster_id: &str,
+    moderator_id: &str,
+    data: BanData,
+  ) -> Result<BanResult, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("moderator_id", moderator_id)];
+
+    Ok(
+      self
+        .api
+        .request(Method::Post, APIEndpoint::Bans, &query)
+        .json(BanRequest { data })
+        .one()
+        .map_err(ban_error)?,
+    )
+  }
+}
+
+// Twitch answers these with a plain 400, only the message tells them apart.
+const ALREADY_BANNED: &str = "The user specified in the user_id field is already banned.";
+const NOT_BANNED: &str = "The user specified in the user_id field is not banned.";
+const CANNOT_BE_BANNED: &str = "The user specified in the user_id field may not be banned.";
+
+fn ban_error(error: APIError) -> APIError {
+  match error {
+    APIError::BadRequest(message) => match message.trim() {
+      ALREADY_BANNED => APIError::AlreadyBanned,
+      NOT_BANNED => APIError::NotBanned,
+      CANNOT_BE_BANNED => APIError::CannotBeBanned,
+      _ => APIError::BadRequest(message),
+    },
+    error => error,
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Debug)]
+struct BanRequest<'a> {
+  data: BanData<'a>,
+}
+
+#[derive(Serialize, Debug)]
+struct BanData<'a> {
+  user_id:  &'a str,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  duration: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  reason:   Option<&'a str>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BanResult {
+  pub broadcaster_id: String,
+  pub moderator_id:   String,
+  pub user_id:        String,
+  pub created_at:     String,
+  pub end_time:       Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BannedUser {
+  #[serde(flatten)]
+  pub user:       User,
+  #[serde(flatten, with = "moderator")]
+  pub moderator:  User,
+  pub reason:     String,
+  pub created_at: String,
+  pub expires_at: String,
+}
+
+#[derive(Serialize, Debug)]
+struct ShieldModeRequest {
+  is_active: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ShieldModeStatus {
+  pub is_active:         bool,
+  #[serde(flatten, with = "moderator")]
+  pub moderator:         User,
+  pub last_activated_at: String,
+}
+
+#[derive(Serialize, Debug)]
+struct WarnRequest<'a> {
+  data: WarnData<'a>,
+}
+
+#[derive(Serialize, Debug)]
+struct WarnData<'a> {
+  user_id: &'a str,
+  reason:  &'a str,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Warning {
+  pub broadcaster_id: String,
+  pub user_id:        String,
+  pub moderator_id:   String,
+  pub reason:         String,
+}
+
+#[derive(Serialize, Debug)]
+struct SuspiciousStatusRequest<'a> {
+  user_id: &'a str,
+  status:  SuspiciousStatus,
+}
+
+/// The statuses a user can be marked with, use [`Twitch::remove_suspicious_status`] to clear it.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SuspiciousStatus {
+  ActiveMonitoring,
+  Restricted,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SuspiciousUser {
+  pub user_id:        String,
+  pub broadcaster_id: String,
+  pub moderator_id:   String,
+  pub updated_at:     String,
+  pub status:         TrustStatus,
+  pub types:          Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UnbanRequestResolution {
+  Approved,
+  Denied,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UnbanRequest {
+  pub id:              String,
+  #[serde(flatten, with = "broadcaster")]
+  pub broadcaster:     User,
+  #[serde(flatten, with = "moderator::option")]
+  pub moderator:       Option<User>,
+  #[serde(flatten)]
+  pub user:            User,
+  pub text:            String,
+  pub status:          UnbanStatus,
+  pub created_at:      String,
+  pub resolved_at:     Option<String>,
+  pub resolution_text: Option<String>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn ban_user() {
+    let response = r#"
+    {
+      "broadcaster_id": "1234",
+      "moderator_id": "5678",
+      "user_id": "9876",
+      "created_at": "2021-09-28T19:27:31Z",
+      "end_time": "2021-09-28T19:22:31Z"
+    }"#;
+    serde_json::from_str::<BanResult>(response).unwrap();
+  }
+
+  #[test]
+  fn get_banned_users() {
+    let response = r#"
+    {
+      "user_id": "423374343",
+      "user_login": "glowillig",
+      "user_name": "glowillig",
+      "expires_at": "2022-03-15T02:00:28Z",
+      "created_at": "2022-03-15T01:30:28Z",
+      "reason": "Does not like pineapple on pizza.",
+      "moderator_id": "141981764",
+      "moderator_login": "twitchdev",
+      "moderator_name": "TwitchDev"
+    }"#;
+    serde_json::from_str::<BannedUser>(response).unwrap();
+  }
+
+  #[test]
+  fn get_shield_mode_status() {
+    let response = r#"
+    {
+      "is_active": true,
+      "moderator_id": "98765",
+      "moderator_name": "SimplySimple",
+      "moderator_login": "simplysimple",
+      "last_activated_at": "2022-07-26T17:16:03.123Z"
+    }"#;
+    serde_json::from_str::<ShieldModeStatus>(response).unwrap();
+  }
+
+  #[test]
+  fn warn_chat_user() {
+    let response = r#"
+    {
+      "broadcaster_id": "404040",
+      "user_id": "9876",
+      "moderator_id": "404041",
+      "reason": "stop doing that!"
+    }"#;
+    serde_json::from_str::<Warning>(response).unwrap();
+  }
+
+  #[test]
+  fn add_suspicious_status() {
+    let response = r#"
+    {
+      "user_id": "9876",
+      "broadcaster_id": "1234",
+      "moderator_id": "5678",
+      "updated_at": "2025-03-10T17:39:14Z",
+      "status": "ACTIVE_MONITORING",
+      "types": ["MANUALLY_ADDED"]
+    }"#;
+    let user = serde_json::from_str::<SuspiciousUser>(response).unwrap();
+    assert_eq!(user.status, TrustStatus::ActiveMonitoring);
+  }
+
+  #[test]
+  fn get_unban_requests() {
+    let response = r#"
+    {
+      "id": "92af127c-7326-4483-a52b-b0da0be61c01",
+      "broadcaster_name": "torpedo09",
+      "broadcaster_login": "torpedo09",
+      "broadcaster_id": "274637212",
+      "moderator_id": null,
+      "moderator_login": null,
+      "moderator_name": null,
+      "user_id": "1337",
+      "user_login": "cool_user",
+      "user_name": "Cool_User",
+      "text": "Please unban me from the channel?",
+      "status": "pending",
+      "created_at": "2022-08-07T02:07:55Z",
+      "resolved_at": null,
+      "resolution_text": null
+    }"#;
+    let request = serde_json::from_str::<UnbanRequest>(response).unwrap();
+    assert!(request.moderator.is_none());
+    assert_eq!(request.user.user_id, "1337");
+
+    let response = r#"
+    {
+      "id": "92af127c-7326-4483-a52b-b0da0be61c01",
+      "broadcaster_name": "torpedo09",
+      "broadcaster_login": "torpedo09",
+      "broadcaster_id": "274637212",
+      "moderator_id": "141981764",
+      "moderator_login": "twitchdev",
+      "moderator_name": "TwitchDev",
+      "user_id": "1337",
+      "user_login": "cool_user",
+      "user_name": "Cool_User",
+      "text": "Please unban me from the channel?",
+      "status": "approved",
+      "created_at": "2022-08-07T02:07:55Z",
+      "resolved_at": "2022-08-09T02:07:55Z",
+      "resolution_text": "We'll give you another chance"
+    }"#;
+    let request = serde_json::from_str::<UnbanRequest>(response).unwrap();
+    assert_eq!(request.moderator.unwrap().user_id, "141981764");
+  }
+
+  #[test]
+  fn ban_errors() {
+    let error =
+      APIError::BadRequest("The user specified in the user_id field is already banned.".to_owned());
+    assert_eq!(ban_error(error), APIError::AlreadyBanned);
+
+    let error =
+      APIError::BadRequest("The user specified in the user_id field may not be banned.".to_owned());
+    assert_eq!(ban_error(error), APIError::CannotBeBanned);
+
+    let error =
+      APIError::BadRequest("The user specified in the user_id field is not banned.".to_owned());
+    assert_eq!(ban_error(error), APIError::NotBanned);
+
+    let message = "The value in the duration field may not be greater than 1209600.";
+    let error = APIError::BadRequest(message.to_owned());
+    assert_eq!(ban_error(error), APIError::BadRequest(message.to_owned()));
+  }
+}
@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, APIError, Method, broadcaster};
+use crate::{Choice, PollStatus, Twitch, TwitchError, User};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Requires `Scope::ChannelReadPolls` or `Scope::ChannelManagePolls`.
+  pub fn get_polls(
+    &self,
+    broadcaster_id: &str,
+    poll_ids: &[&str],
+  ) -> Result<Vec<Poll>, TwitchError> {
+    let mut query = vec![("broadcaster_id", broadcaster_id), ("first", "20")];
+    query.extend(poll_ids.iter().map(|&id| ("id", id)));
+
+    Ok(self.api.get(APIEndpoint::Polls, &query)?)
+  }
+
+  /// Polls need 2 to 5 choices and may run between 15 and 1800 seconds, which is checked before
+  /// the request is sent.
+  ///
+  /// Requires `Scope::ChannelManagePolls`.
+  pub fn create_poll(
+    &self,
+    broadcaster_id: &str,
+    title: &str,
+    choices: &[&str],
+    duration: u32,
+    channel_points_per_vote: Option<u32>,
+  ) -> Result<Poll, TwitchError> {
+    let request = CreatePoll {
+      broadcaster_id,
+      title,
+      choices: choices.iter().map(|&title| NewChoice { title }).collect(),
+      duration,
+      channel_points_voting_enabled: channel_points_per_vote.is_some(),
+      channel_points_per_vote,
+    };
+    request.validate()?;
+
+    Ok(self.api.request(Method::Post, APIEndpoint::Polls, &[]).json(request).one()?)
+  }
+
+  /// Requires `Scope::ChannelManagePolls`.
+  pub fn end_poll(
+    &self,
+    broadcaster_id: &str,
+    poll_id: &str,
+    status: PollEndStatus,
+  ) -> Result<Poll, TwitchError> {
+    let request = EndPoll { broadcaster_id, id: poll_id, status };
+
+    Ok(self.api.request(Method::Patch, APIEndpoint::Polls, &[]).json(request).one()?)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Debug)]
+struct CreatePoll<'a> {
+  broadcaster_id: &'a str,
+  title: &'a str,
+  choices: Vec<NewChoice<'a>>,
+  duration: u32,
+  channel_points_voting_enabled: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  channel_points_per_vote: Option<u32>,
+}
+
+#[derive(Serialize, Debug)]
+struct NewChoice<'a> {
+  title: &'a str,
+}
+
+impl CreatePoll<'_> {
+  fn validate(&self) -> Result<(), APIError> {
+    let invalid = |message: &str| Err(APIError::InvalidRequest(message.to_owned()));
+
+    if self.title.is_empty() || self.title.chars().count() > 60 {
+      return invalid("poll title must be between 1 and 60 characters");
+    }
+    if !(2..=5).contains(&self.choices.len()) {
+      return invalid("poll must have between 2 and 5 choices");
+    }
+    if self.choices.iter().any(|c| c.title.is_empty() || c.title.chars().count() > 25) {
+      return invalid("poll choice titles must be between 1 and 25 characters");
+    }
+    if !(15..=1800).contains(&self.duration) {
+      return invalid("poll duration must be between 15 and 1800 seconds");
+    }
+    if self.channel_points_per_vote.is_some_and(|points| !(1..=1_000_000).contains(&points)) {
+      return invalid("channel points per vote must be between 1 and 1000000");
+    }
+
+    Ok(())
+  }
+}
+
+#[derive(Serialize, Debug)]
+struct EndPoll<'a> {
+  broadcaster_id: &'a str,
+  id:             &'a str,
+  status:         PollEndStatus,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PollEndStatus {
+  Terminated,
+  Archived,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Poll {
+  pub id: String,
+  #[serde(flatten, with = "broadcaster")]
+  pub broadcaster: User,
+  pub title: String,
+  pub choices: Vec<Choice>,
+  pub channel_points_voting_enabled: bool,
+  pub channel_points_per_vote: u64,
+  pub status: PollStatus,
+  pub duration: u32,
+  pub started_at: String,
+  pub ended_at: Option<String>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn get_polls() {
+    let response = r#"
+    {
+      "id": "ed961efd-8a3f-4cf5-a9d0-e616c590cd2a",
+      "broadcaster_id": "55696719",
+      "broadcaster_name": "TwitchDev",
+      "broadcaster_login": "twitchdev",
+      "title": "Heads or Tails?",
+      "choices": [
+        {
+          "id": "4c123012-1351-4f33-84b7-43856e7a0f47",
+          "title": "Heads",
+          "votes": 0,
+          "channel_points_votes": 0,
+          "bits_votes": 0
+        },
+        {
+          "id": "279087e3-54a7-467e-bcd0-c1393fcea4f0",
+          "title": "Tails",
+          "votes": 0,
+          "channel_points_votes": 0,
+          "bits_votes": 0
+        }
+      ],
+      "bits_voting_enabled": false,
+      "bits_per_vote": 0,
+      "channel_points_voting_enabled": false,
+      "channel_points_per_vote": 0,
+      "status": "ACTIVE",
+      "duration": 1800,
+      "started_at": "2021-03-19T06:08:33.871278372Z",
+      "ended_at": null
+    }"#;
+    let poll = serde_json::from_str::<Poll>(response).unwrap();
+    assert!(matches!(poll.status, PollStatus::Active));
+  }
+
+  #[test]
+  fn validate_poll() {
+    let poll = |choices: Vec<&'static str>, duration| CreatePoll {
+      broadcaster_id: "1337",
+      title: "Heads or Tails?",
+      choices: choices.into_iter().map(|title| NewChoice { title }).collect(),
+      duration,
+      channel_points_voting_enabled: false,
+      channel_points_per_vote: None,
+    };
+
+    assert!(poll(vec!["Heads", "Tails"], 1800).validate().is_ok());
+    assert!(poll(vec!["Heads"], 1800).validate().is_err());
+    assert!(poll(vec!["Heads", "Tails"], 10).validate().is_err());
+    assert!(poll(vec!["Heads", "Tails, but only on a tuesday"], 60).validate().is_err());
+  }
+}
@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, APIError, Method, broadcaster};
+use crate::{Outcome, PredictionStatus, Twitch, TwitchError, User};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Requires `Scope::ChannelReadPredictions` or `Scope::ChannelManagePredictions`.
+  pub fn get_predictions(
+    &self,
+    broadcaster_id: &str,
+    prediction_ids: &[&str],
+  ) -> Result<Vec<Prediction>, TwitchError> {
+    let mut query = vec![("broadcaster_id", broadcaster_id), ("first", "25")];
+    query.extend(prediction_ids.iter().map(|&id| ("id", id)));
+
+    Ok(self.api.get(APIEndpoint::Predictions, &query)?)
+  }
+
+  /// Predictions need 2 to 10 outcomes and a prediction window between 30 and 1800 seconds, which
+  /// is checked before the request is sent.
+  ///
+  /// Requires `Scope::ChannelManagePredictions`.
+  pub fn create_prediction(
+    &self,
+    broadcaster_id: &str,
+    title: &str,
+    outcomes: &[&str],
+    prediction_window: u32,
+  ) -> Result<Prediction, TwitchError> {
+    let request = CreatePrediction {
+      broadcaster_id,
+      title,
+      outcomes: outcomes.iter().map(|&title| NewOutcome { title }).collect(),
+      prediction_window,
+    };
+    request.validate()?;
+
+    Ok(self.api.request(Method::Post, APIEndpoint::Predictions, &[]).json(request).one()?)
+  }
+
+  /// Requires `Scope::ChannelManagePredictions`.
+  pub fn end_prediction(
+    &self,
+    broadcaster_id: &str,
+    prediction_id: &str,
+    status: PredictionEndStatus,
+  ) -> Result<Prediction, TwitchError> {
+    let (status, winning_outcome_id) = match &status {
+      PredictionEndStatus::Resolved(id) => ("RESOLVED", Some(id.as_str())),
+      PredictionEndStatus::Canceled => ("CANCELED", None),
+      PredictionEndStatus::Locked => ("LOCKED", None),
+    };
+    let request = EndPrediction { broadcaster_id, id: prediction_id, status, winning_outcome_id };
+
+    Ok(self.api.request(Method::Patch, APIEndpoint::Predictions, &[]).json(request).one()?)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Debug)]
+struct CreatePrediction<'a> {
+  broadcaster_id:    &'a str,
+  title:             &'a str,
+  outcomes:          Vec<NewOutcome<'a>>,
+  prediction_window: u32,
+}
+
+#[derive(Serialize, Debug)]
+struct NewOutcome<'a> {
+  title: &'a str,
+}
+
+impl CreatePrediction<'_> {
+  fn validate(&self) -> Result<(), APIError> {
+    let invalid = |message: &str| Err(APIError::InvalidRequest(message.to_owned()));
+
+    if self.title.is_empty() || self.title.chars().count() > 45 {
+      return invalid("prediction title must be between 1 and 45 characters");
+    }
+    if !(2..=10).contains(&self.outcomes.len()) {
+      return invalid("prediction must have between 2 and 10 outcomes");
+    }
+    if self.outcomes.iter().any(|o| o.title.is_empty() || o.title.chars().count() > 25) {
+      return invalid("prediction outcome titles must be between 1 and 25 characters");
+    }
+    if !(30..=1800).contains(&self.prediction_window) {
+      return invalid("prediction window must be between 30 and 1800 seconds");
+    }
+
+    Ok(())
+  }
+}
+
+#[derive(Serialize, Debug)]
+struct EndPrediction<'a> {
+  broadcaster_id:     &'a str,
+  id:                 &'a str,
+  status:             &'a str,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  winning_outcome_id: Option<&'a str>,
+}
+
+/// How to end a prediction, resolving it requires the id of the winning outcome.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PredictionEndStatus {
+  Resolved(String),
+  Canceled,
+  Locked,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Prediction {
+  pub id:                 String,
+  #[serde(flatten, with = "broadcaster")]
+  pub broadcaster:        User,
+  pub title:              String,
+  pub winning_outcome_id: Option<String>,
+  pub outcomes:           Vec<Outcome>,
+  pub prediction_window:  u32,
+  pub status:             PredictionStatus,
+  pub created_at:         String,
+  pub ended_at:           Option<String>,
+  pub locked_at:          Option<String>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn get_predictions() {
+    let response = r#"
+    {
+      "id": "d6676d5c-c86e-44d2-bfc4-100fb48f0656",
+      "broadcaster_id": "55696719",
+      "broadcaster_name": "TwitchDev",
+      "broadcaster_login": "twitchdev",
+      "title": "Will there be any leaks today?",
+      "winning_outcome_id": null,
+      "outcomes": [
+        {
+          "id": "021e9234-5893-49b4-982e-cfe9a0aaddd9",
+          "title": "Yes",
+          "users": 0,
+          "channel_points": 0,
+          "top_predictors": null,
+          "color": "BLUE"
+        },
+        {
+          "id": "ded84c26-13cb-4b48-8cb5-5bae3ec3a66e",
+          "title": "No",
+          "users": 0,
+          "channel_points": 0,
+          "top_predictors": null,
+          "color": "PINK"
+        }
+      ],
+      "prediction_window": 600,
+      "status": "ACTIVE",
+      "created_at": "2021-04-28T16:03:06.320848689Z",
+      "ended_at": null,
+      "locked_at": null
+    }"#;
+    let prediction = serde_json::from_str::<Prediction>(response).unwrap();
+    assert!(matches!(prediction.status, PredictionStatus::Active));
+    assert!(prediction.outcomes[0].top_predictors.is_empty());
+  }
+
+  #[test]
+  fn validate_prediction() {
+    let prediction = |outcomes: Vec<&'static str>, prediction_window| CreatePrediction {
+      broadcaster_id: "1337",
+      title: "Will there be any leaks today?",
+      outcomes: outcomes.into_iter().map(|title| NewOutcome { title }).collect(),
+      prediction_window,
+    };
+
+    assert!(prediction(vec!["Yes", "No"], 600).validate().is_ok());
+    assert!(prediction(vec!["Yes"], 600).validate().is_err());
+    assert!(prediction(vec!["Yes", "No"], 10).validate().is_err());
+  }
+}
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, APIError, Method};
+use crate::{Twitch, TwitchError};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Requires `Scope::ChannelManageRaids`.
+  pub fn start_raid(
+    &self,
+    from_broadcaster_id: &str,
+    to_broadcaster_id: &str,
+  ) -> Result<StartedRaid, TwitchError> {
+    let query =
+      [("from_broadcaster_id", from_broadcaster_id), ("to_broadcaster_id", to_broadcaster_id)];
+
+    Ok(self.api.request(Method::Post, APIEndpoint::Raids, &query).one().map_err(raid_error)?)
+  }
+
+  /// Requires `Scope::ChannelManageRaids`.
+  pub fn start_raid_by_login(
+    &self,
+    from_broadcaster_id: &str,
+    to_broadcaster_login: &str,
+  ) -> Result<StartedRaid, TwitchError> {
+    let target = self.get_user_by_login(to_broadcaster_login)?;
+    let target = target.ok_or_else(|| APIError::NotFound(to_broadcaster_login.to_owned()))?;
+
+    self.start_raid(from_broadcaster_id, &target.user.user_id)
+  }
+
+  /// Requires `Scope::ChannelManageRaids`.
+  pub fn cancel_raid(&self, broadcaster_id: &str) -> Result<(), TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id)];
+
+    Ok(self.api.request(Method::Delete, APIEndpoint::Raids, &query).empty().map_err(raid_error)?)
+  }
+}
+
+fn raid_error(error: APIError) -> APIError {
+  match error {
+    APIError::Conflict(_) => APIError::AlreadyRaiding,
+    APIError::NotFound(message) if message.contains("pending raid") => APIError::NoPendingRaid,
+    error => error,
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StartedRaid {
+  pub created_at: String,
+  pub is_mature:  bool,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn start_raid() {
+    let response = r#"
+    {
+      "created_at": "2022-02-18T07:20:50.52Z",
+      "is_mature": false
+    }"#;
+    serde_json::from_str::<StartedRaid>(response).unwrap();
+  }
+
+  #[test]
+  fn raid_errors() {
+    let error = APIError::Conflict("The broadcaster is already raiding".to_owned());
+    assert_eq!(raid_error(error), APIError::AlreadyRaiding);
+
+    let error = APIError::NotFound("The broadcaster doesn't have a pending raid".to_owned());
+    assert_eq!(raid_error(error), APIError::NoPendingRaid);
+    assert_eq!(raid_error(APIError::TooManyRequests), APIError::TooManyRequests);
+  }
+}
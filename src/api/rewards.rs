@@ -0,0 +1,532 @@
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, Method, broadcaster, query_value};
+use crate::{
+  CustomReward, Event, GlobalCooldown, Image, MaxPerStream, PointsCustomRewardRedemptionAdd,
+  PointsCustomRewardRedemptionUpdate, RedeemStatus, Twitch, TwitchError, User,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Requires `Scope::ChannelManageRedemptions`.
+  pub fn create_custom_reward(
+    &self,
+    broadcaster_id: &str,
+    reward: &RewardBuilder,
+  ) -> Result<ChannelReward, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id)];
+
+    Ok(self.api.request(Method::Post, APIEndpoint::CustomRewards, &query).json(reward).one()?)
+  }
+
+  /// Requires `Scope::ChannelManageRedemptions`.
+  pub fn update_custom_reward(
+    &self,
+    broadcaster_id: &str,
+    reward_id: &str,
+    reward: &RewardBuilder,
+  ) -> Result<ChannelReward, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("id", reward_id)];
+
+    Ok(self.api.request(Method::Patch, APIEndpoint::CustomRewards, &query).json(reward).one()?)
+  }
+
+  /// Requires `Scope::ChannelManageRedemptions`.
+  pub fn delete_custom_reward(
+    &self,
+    broadcaster_id: &str,
+    reward_id: &str,
+  ) -> Result<(), TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("id", reward_id)];
+
+    Ok(self.api.request(Method::Delete, APIEndpoint::CustomRewards, &query).empty()?)
+  }
+
+  /// Requires `Scope::ChannelReadRedemptions` or `Scope::ChannelManageRedemptions`.
+  pub fn get_custom_rewards(
+    &self,
+    broadcaster_id: &str,
+    reward_ids: &[&str],
+    only_manageable_rewards: bool,
+  ) -> Result<Vec<ChannelReward>, TwitchError> {
+    let only_manageable_rewards = only_manageable_rewards.to_string();
+    let mut query = vec![
+      ("broadcaster_id", broadcaster_id),
+      ("only_manageable_rewards", &only_manageable_rewards),
+    ];
+    query.extend(reward_ids.iter().map(|&id| ("id", id)));
+
+    Ok(self.api.request(Method::Get, APIEndpoint::CustomRewards, &query).list()?)
+  }
+
+  /// Requires `Scope::ChannelReadRedemptions` or `Scope::ChannelManageRedemptions`.
+  pub fn get_custom_reward_redemptions(
+    &self,
+    broadcaster_id: &str,
+    reward_id: &str,
+    status: RedeemStatus,
+    sort: RedemptionSort,
+  ) -> Result<Vec<Redemption>, TwitchError> {
+    let sort = query_value(&sort);
+    let query = [
+      ("broadcaster_id", broadcaster_id),
+      ("reward_id", reward_id),
+      ("status", helix_status(&status)),
+      ("sort", &sort),
+      ("first", "50"),
+    ];
+
+    Ok(self.api.get(APIEndpoint::Redemptions, &query)?)
+  }
+
+  /// Requires `Scope::ChannelReadRedemptions` or `Scope::ChannelManageRedemptions`.
+  pub fn get_custom_reward_redemptions_by_id(
+    &self,
+    broadcaster_id: &str,
+    reward_id: &str,
+    redemption_ids: &[&str],
+  ) -> Result<Vec<Redemption>, TwitchError> {
+    let mut redemptions = Vec::new();
+    for ids in redemption_ids.chunks(50) {
+      let mut query = vec![("broadcaster_id", broadcaster_id), ("reward_id", reward_id)];
+      query.extend(ids.iter().map(|&id| ("id", id)));
+      redemptions.extend(self.api.request(Method::Get, APIEndpoint::Redemptions, &query).list()?);
+    }
+
+    Ok(redemptions)
+  }
+
+  /// Marks redemptions as fulfilled or canceled, canceling refunds the spent channel points.
+  ///
+  /// Requires `Scope::ChannelManageRedemptions`.
+  pub fn update_redemption_status(
+    &self,
+    broadcaster_id: &str,
+    reward_id: &str,
+    redemption_ids: &[&str],
+    status: RedemptionUpdateStatus,
+  ) -> Result<Vec<Redemption>, TwitchError> {
+    let request = UpdateRedemptionStatus { status };
+
+    let mut redemptions = Vec::new();
+    for ids in redemption_ids.chunks(50) {
+      let mut query = vec![("broadcaster_id", broadcaster_id), ("reward_id", reward_id)];
+      query.extend(ids.iter().map(|&id| ("id", id)));
+      redemptions.extend(
+        self.api.request(Method::Patch, APIEndpoint::Redemptions, &query).json(&request).list()?,
+      );
+    }
+
+    Ok(redemptions)
+  }
+
+  /// Requires `Scope::ChannelReadRedemptions` or `Scope::ChannelManageRedemptions`.
+  pub fn get_redemption_queue(
+    &self,
+    broadcaster_id: &str,
+    reward_id: &str,
+  ) -> Result<RedemptionQueue, TwitchError> {
+    let redemptions = self.get_custom_reward_redemptions(
+      broadcaster_id,
+      reward_id,
+      RedeemStatus::Unfulfilled,
+      RedemptionSort::Oldest,
+    )?;
+
+    Ok(RedemptionQueue::new(broadcaster_id.to_owned(), reward_id.to_owned(), redemptions))
+  }
+}
+
+fn helix_status(status: &RedeemStatus) -> &'static str {
+  match status {
+    RedeemStatus::Unfulfilled => "UNFULFILLED",
+    RedeemStatus::Fulfilled => "FULFILLED",
+    RedeemStatus::Canceled => "CANCELED",
+    RedeemStatus::Unknown => "UNKNOWN",
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChannelReward {
+  pub id: String,
+  #[serde(flatten, with = "broadcaster")]
+  pub broadcaster: User,
+  pub is_enabled: bool,
+  pub is_paused: bool,
+  pub is_in_stock: bool,
+  pub title: String,
+  pub cost: u64,
+  pub prompt: String,
+  pub is_user_input_required: bool,
+  pub should_redemptions_skip_request_queue: bool,
+  #[serde(rename = "max_per_stream_setting")]
+  pub max_per_stream: MaxPerStream,
+  #[serde(rename = "max_per_user_per_stream_setting")]
+  pub max_per_user_per_stream: MaxPerStream,
+  pub background_color: String,
+  pub image: Option<Image>,
+  pub default_image: Image,
+  #[serde(rename = "global_cooldown_setting")]
+  pub global_cooldown: GlobalCooldown,
+  pub cooldown_expires_at: Option<String>,
+  pub redemptions_redeemed_current_stream: Option<u64>,
+}
+
+#[derive(Serialize, Debug)]
+struct UpdateRedemptionStatus {
+  status: RedemptionUpdateStatus,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RedemptionUpdateStatus {
+  Fulfilled,
+  Canceled,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum RedemptionSort {
+  Oldest,
+  Newest,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Redemption {
+  pub id:          String,
+  #[serde(flatten, with = "broadcaster")]
+  pub broadcaster: User,
+  #[serde(flatten)]
+  pub user:        User,
+  pub user_input:  String,
+  pub status:      RedeemStatus,
+  pub reward:      CustomReward,
+  pub redeemed_at: String,
+}
+
+impl From<&PointsCustomRewardRedemptionAdd> for Redemption {
+  fn from(event: &PointsCustomRewardRedemptionAdd) -> Self {
+    Self {
+      id:          event.id.clone(),
+      broadcaster: event.broadcaster.clone(),
+      user:        event.user.clone(),
+      user_input:  event.user_input.clone(),
+      status:      event.status.clone(),
+      reward:      event.reward.clone(),
+      redeemed_at: event.redeemed_at.clone(),
+    }
+  }
+}
+
+/// Unfulfilled redemptions of a single reward, oldest first, kept up to date by feeding it eventsub
+/// events.
+#[derive(Debug, Clone)]
+pub struct RedemptionQueue {
+  broadcaster_id: String,
+  reward_id:      String,
+  redemptions:    VecDeque<Redemption>,
+}
+
+impl RedemptionQueue {
+  /// Keeps only the unfulfilled redemptions, so the result of any redemption listing can be passed.
+  pub fn new(broadcaster_id: String, reward_id: String, redemptions: Vec<Redemption>) -> Self {
+    let mut redemptions: Vec<_> =
+      redemptions.into_iter().filter(|r| matches!(r.status, RedeemStatus::Unfulfilled)).collect();
+    redemptions.sort_by(|a, b| a.redeemed_at.cmp(&b.redeemed_at));
+
+    Self { broadcaster_id, reward_id, redemptions: redemptions.into() }
+  }
+
+  pub fn broadcaster_id(&self) -> &str {
+    &self.broadcaster_id
+  }
+
+  pub fn reward_id(&self) -> &str {
+    &self.reward_id
+  }
+
+  /// The oldest unfulfilled redemption.
+  pub fn front(&self) -> Option<&Redemption> {
+    self.redemptions.front()
+  }
+
+  pub fn get(&self, redemption_id: &str) -> Option<&Redemption> {
+    self.redemptions.iter().find(|r| r.id == redemption_id)
+  }
+
+  pub fn redemptions(&self) -> impl Iterator<Item = &Redemption> {
+    self.redemptions.iter()
+  }
+
+  pub fn len(&self) -> usize {
+    self.redemptions.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.redemptions.is_empty()
+  }
+
+  pub fn handle_event(&mut self, event: &Event) {
+    match event {
+      Event::PointsCustomRewardRedemptionAdd(e) if self.is_tracked(&e.broadcaster, &e.reward) =>
+        self.add(e),
+      Event::PointsCustomRewardRedemptionUpdate(e)
+        if self.is_tracked(&e.broadcaster, &e.reward) =>
+        self.remove(e),
+      _ => (),
+    }
+  }
+
+  fn is_tracked(&self, broadcaster: &User, reward: &CustomReward) -> bool {
+    broadcaster.user_id == self.broadcaster_id && reward.id == self.reward_id
+  }
+
+  fn add(&mut self, redemption: &PointsCustomRewardRedemptionAdd) {
+    if matches!(redemption.status, RedeemStatus::Unfulfilled) && self.get(&redemption.id).is_none()
+    {
+      self.redemptions.push_back(redemption.into());
+    }
+  }
+
+  fn remove(&mut self, update: &PointsCustomRewardRedemptionUpdate) {
+    if !matches!(update.status, RedeemStatus::Unfulfilled) {
+      self.redemptions.retain(|r| r.id != update.id);
+    }
+  }
+}
+
+/// Settings of a custom reward, used both for creating rewards and for partially updating them.
+///
+/// Only the fields that were set are sent to twitch, so [`RewardBuilder::default`] can be used to
+/// change individual settings of an existing reward.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct RewardBuilder {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  title: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  cost: Option<u64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  prompt: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  is_enabled: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  is_paused: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  background_color: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  is_user_input_required: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  is_max_per_stream_enabled: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  max_per_stream: Option<u64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  is_max_per_user_per_stream_enabled: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  max_per_user_per_stream: Option<u64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  is_global_cooldown_enabled: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  global_cooldown_seconds: Option<u64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  should_redemptions_skip_request_queue: Option<bool>,
+}
+
+impl RewardBuilder {
+  pub fn new(title: impl Into<String>, cost: u64) -> Self {
+    Self::default().title(title).cost(cost)
+  }
+
+  pub fn title(mut self, title: impl Into<String>) -> Self {
+    self.title = Some(title.into());
+    self
+  }
+
+  pub fn cost(mut self, cost: u64) -> Self {
+    self.cost = Some(cost);
+    self
+  }
+
+  pub fn prompt(mut self, prompt: impl Into<String>) -> Self {
+    self.prompt = Some(prompt.into());
+    self
+  }
+
+  pub fn enabled(mut self, is_enabled: bool) -> Self {
+    self.is_enabled = Some(is_enabled);
+    self
+  }
+
+  pub fn paused(mut self, is_paused: bool) -> Self {
+    self.is_paused = Some(is_paused);
+    self
+  }
+
+  /// Hex color such as `#9147FF`.
+  pub fn background_color(mut self, background_color: impl Into<String>) -> Self {
+    self.background_color = Some(background_color.into());
+    self
+  }
+
+  pub fn user_input_required(mut self, is_user_input_required: bool) -> Self {
+    self.is_user_input_required = Some(is_user_input_required);
+    self
+  }
+
+  /// `None` removes the limit.
+  pub fn max_per_stream(mut self, max_per_stream: Option<u64>) -> Self {
+    self.is_max_per_stream_enabled = Some(max_per_stream.is_some());
+    self.max_per_stream = max_per_stream;
+    self
+  }
+
+  /// `None` removes the limit.
+  pub fn max_per_user_per_stream(mut self, max_per_user_per_stream: Option<u64>) -> Self {
+    self.is_max_per_user_per_stream_enabled = Some(max_per_user_per_stream.is_some());
+    self.max_per_user_per_stream = max_per_user_per_stream;
+    self
+  }
+
+  /// Cooldown between redemptions by anyone, `None` removes it.
+  pub fn global_cooldown(mut self, seconds: Option<u64>) -> Self {
+    self.is_global_cooldown_enabled = Some(seconds.is_some());
+    self.global_cooldown_seconds = seconds;
+    self
+  }
+
+  /// Redemptions are marked fulfilled right away instead of waiting in the queue.
+  pub fn skip_request_queue(mut self, should_redemptions_skip_request_queue: bool) -> Self {
+    self.should_redemptions_skip_request_queue = Some(should_redemptions_skip_request_queue);
+    self
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::eventsub::events::notification;
+
+  #[test]
+  fn get_custom_rewards() {
+    let response = r##"
+    {
+      "broadcaster_name": "torpedo09",
+      "broadcaster_login": "torpedo09",
+      "broadcaster_id": "274637212",
+      "id": "92af127c-7326-4483-a52b-b0da0be61c01",
+      "image": null,
+      "background_color": "#00E5CB",
+      "is_enabled": true,
+      "cost": 50000,
+      "title": "game analysis",
+      "prompt": "",
+      "is_user_input_required": false,
+      "max_per_stream_setting": {
+        "is_enabled": false,
+        "max_per_stream": 0
+      },
+      "max_per_user_per_stream_setting": {
+        "is_enabled": false,
+        "max_per_user_per_stream": 0
+      },
+      "global_cooldown_setting": {
+        "is_enabled": false,
+        "global_cooldown_seconds": 0
+      },
+      "is_paused": false,
+      "is_in_stock": true,
+      "default_image": {
+        "url_1x": "https://static-cdn.jtvnw.net/custom-reward-images/default-1.png",
+        "url_2x": "https://static-cdn.jtvnw.net/custom-reward-images/default-2.png",
+        "url_4x": "https://static-cdn.jtvnw.net/custom-reward-images/default-4.png"
+      },
+      "should_redemptions_skip_request_queue": false,
+      "redemptions_redeemed_current_stream": null,
+      "cooldown_expires_at": null
+    }"##;
+    serde_json::from_str::<ChannelReward>(response).unwrap();
+  }
+
+  #[test]
+  fn reward_builder() {
+    let reward = RewardBuilder::new("game analysis 1v1", 50000).global_cooldown(Some(60));
+    let reward = serde_json::to_value(&reward).unwrap();
+    assert_eq!(reward["title"], "game analysis 1v1");
+    assert_eq!(reward["is_global_cooldown_enabled"], true);
+    assert_eq!(reward["global_cooldown_seconds"], 60);
+    assert!(reward.get("prompt").is_none());
+
+    let reward = RewardBuilder::default().max_per_stream(None);
+    let reward = serde_json::to_value(&reward).unwrap();
+    assert_eq!(reward["is_max_per_stream_enabled"], false);
+    assert!(reward.get("max_per_stream").is_none());
+    assert!(reward.get("cost").is_none());
+  }
+
+  #[test]
+  fn get_custom_reward_redemptions() {
+    let response = r#"
+    {
+      "broadcaster_name": "torpedo09",
+      "broadcaster_login": "torpedo09",
+      "broadcaster_id": "274637212",
+      "id": "17fa2df1-ad76-4804-bfa5-a40ef63efe63",
+      "user_login": "torpedo09",
+      "user_id": "274637212",
+      "user_name": "torpedo09",
+      "user_input": "",
+      "status": "CANCELED",
+      "redeemed_at": "2020-07-01T18:37:32Z",
+      "reward": {
+        "id": "92af127c-7326-4483-a52b-b0da0be61c01",
+        "title": "game analysis",
+        "prompt": "",
+        "cost": 50000
+      }
+    }"#;
+    let redemption = serde_json::from_str::<Redemption>(response).unwrap();
+    assert!(matches!(redemption.status, RedeemStatus::Canceled));
+    let request = UpdateRedemptionStatus { status: RedemptionUpdateStatus::Fulfilled };
+    assert_eq!(serde_json::to_string(&request).unwrap(), r#"{"status":"FULFILLED"}"#);
+  }
+
+  #[test]
+  fn redemption_queue() {
+    let mut queue = RedemptionQueue::new("1337".to_owned(), "92af".to_owned(), vec![]);
+
+    let event = r#"
+    {
+      "id": "17fa2df1-ad76-4804-bfa5-a40ef63efe63",
+      "broadcaster_user_id": "1337",
+      "broadcaster_user_login": "cool_user",
+      "broadcaster_user_name": "Cool_User",
+      "user_id": "9001",
+      "user_login": "cooler_user",
+      "user_name": "Cooler_User",
+      "user_input": "pogchamp",
+      "status": "unfulfilled",
+      "reward": {
+        "id": "92af",
+        "title": "title",
+        "cost": 100,
+        "prompt": "reward prompt"
+      },
+      "redeemed_at": "2020-07-15T17:16:03.17106713Z"
+    }"#;
+    queue.handle_event(&notification("channel.channel_points_custom_reward_redemption.add", event));
+    assert_eq!(queue.front().unwrap().id, "17fa2df1-ad76-4804-bfa5-a40ef63efe63");
+
+    let event = event.replace("\"unfulfilled\"", "\"fulfilled\"");
+    queue.handle_event(&notification(
+      "channel.channel_points_custom_reward_redemption.update",
+      &event,
+    ));
+    assert!(queue.is_empty());
+  }
+}
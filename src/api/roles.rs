@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use super::{APIEndpoint, Method, broadcaster};
+use crate::{Action, Conditions, Event, Subscription, SubscriptionType, Twitch, TwitchError, User};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Requires `Scope::ModerationRead`.
+  pub fn get_moderators(
+    &self,
+    broadcaster_id: &str,
+    user_ids: &[&str],
+  ) -> Result<Vec<User>, TwitchError> {
+    let mut query = vec![("broadcaster_id", broadcaster_id), ("first", "100")];
+    query.extend(user_ids.iter().map(|&id| ("user_id", id)));
+
+    Ok(self.api.get(APIEndpoint::Moderators, &query)?)
+  }
+
+  /// Requires `Scope::ChannelManageModerators`.
+  pub fn add_moderator(&self, broadcaster_id: &str, user_id: &str) -> Result<(), TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("user_id", user_id)];
+
+    Ok(self.api.request(Method::Post, APIEndpoint::Moderators, &query).empty()?)
+  }
+
+  /// Requires `Scope::ChannelManageModerators`.
+  pub fn remove_moderator(&self, broadcaster_id: &str, user_id: &str) -> Result<(), TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("user_id", user_id)];
+
+    Ok(self.api.request(Method::Delete, APIEndpoint::Moderators, &query).empty()?)
+  }
+
+  /// Requires `Scope::ChannelReadVips`.
+  pub fn get_vips(
+    &self,
+    broadcaster_id: &str,
+    user_ids: &[&str],
+  ) -> Result<Vec<User>, TwitchError> {
+    let mut query = vec![("broadcaster_id", broadcaster_id), ("first", "100")];
+    query.extend(user_ids.iter().map(|&id| ("user_id", id)));
+
+    Ok(self.api.get(APIEndpoint::Vips, &query)?)
+  }
+
+  /// Requires `Scope::ChannelManageVips`.
+  pub fn add_vip(&self, broadcaster_id: &str, user_id: &str) -> Result<(), TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("user_id", user_id)];
+
+    Ok(self.api.request(Method::Post, APIEndpoint::Vips, &query).empty()?)
+  }
+
+  /// Requires `Scope::ChannelManageVips`.
+  pub fn remove_vip(&self, broadcaster_id: &str, user_id: &str) -> Result<(), TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("user_id", user_id)];
+
+    Ok(self.api.request(Method::Delete, APIEndpoint::Vips, &query).empty()?)
+  }
+
+  /// Requires `Scope::ModerationRead` and `Scope::ChannelReadVips`.
+  pub fn get_channel_roster(&self, broadcaster_id: &str) -> Result<ChannelRoster, TwitchError> {
+    let moderators = self.get_moderators(broadcaster_id, &[])?;
+    let vips = self.get_vips(broadcaster_id, &[])?;
+
+    Ok(ChannelRoster::new(broadcaster_id.to_owned(), moderators, vips))
+  }
+
+  /// Requires `Scope::UserReadModeratedChannels`. The user's own channel isn't included.
+  pub fn get_moderated_channels(&self, user_id: &str) -> Result<Vec<User>, TwitchError> {
+    let query = [("user_id", user_id), ("first", "100")];
+    let channels: Vec<ModeratedChannel> = self.api.get(APIEndpoint::ModeratedChannels, &query)?;
+
+    Ok(channels.into_iter().map(|channel| channel.broadcaster).collect())
+  }
+
+  /// The user's own channel followed by every channel they moderate.
+  ///
+  /// Requires `Scope::UserReadModeratedChannels`.
+  pub fn get_moderatable_channels(&self, user_id: &str) -> Result<Vec<User>, TwitchError> {
+    let mut channels: Vec<User> =
+      self.get_users(&[user_id], &[])?.into_iter().map(|info| info.user).collect();
+    channels.extend(self.get_moderated_channels(user_id)?);
+
+    Ok(channels)
+  }
+
+  /// Subscribes to every subscription type for each channel the user can moderate, using the user
+  /// as moderator. Meant for moderator-scoped types, the token has to belong to `user_id`.
+  ///
+  /// A failed subscription doesn't stop the others, every result is returned so created
+  /// subscriptions can still be used or cleaned up.
+  ///
+  /// Requires `Scope::UserReadModeratedChannels` and the scopes of the subscription types.
+  pub fn subscribe_moderatable_channels(
+    &self,
+    session_id: &str,
+    user_id: &str,
+    subscription_types: &[SubscriptionType],
+  ) -> Result<Vec<ChannelSubscriptions>, TwitchError> {
+    let channels = self.get_moderatable_channels(user_id)?;
+
+    Ok(
+      channels
+        .into_iter()
+        .map(|channel| {
+          let conditions = Conditions::new(channel.user_id.clone(), user_id.to_owned());
+          let subscriptions = subscription_types
+            .iter()
+            .map(|subscription_type| subscription_type.build_subscription(session_id, &conditions))
+            .map(|subscription| self.create_eventsub_subscription(subscription))
+            .collect();
+
+          ChannelSubscriptions { channel, subscriptions }
+        })
+        .collect(),
+    )
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Results of subscribing a single channel, in the order of the requested subscription types.
+#[derive(Debug)]
+pub struct ChannelSubscriptions {
+  pub channel:       User,
+  pub subscriptions: Vec<Result<Subscription, TwitchError>>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ModeratedChannel {
+  #[serde(flatten, with = "broadcaster")]
+  broadcaster: User,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Moderators and VIPs of a single channel, kept up to date by feeding it eventsub events.
+#[derive(Debug, Clone)]
+pub struct ChannelRoster {
+  broadcaster_id: String,
+  moderators:     HashMap<String, User>,
+  vips:           HashMap<String, User>,
+}
+
+impl ChannelRoster {
+  pub fn new(broadcaster_id: String, moderators: Vec<User>, vips: Vec<User>) -> Self {
+    let by_id = |users: Vec<User>| users.into_iter().map(|u| (u.user_id.clone(), u)).collect();
+    Self { broadcaster_id, moderators: by_id(moderators), vips: by_id(vips) }
+  }
+
+  pub fn broadcaster_id(&self) -> &str {
+    &self.broadcaster_id
+  }
+
+  pub fn is_moderator(&self, user_id: &str) -> bool {
+    self.moderators.contains_key(user_id)
+  }
+
+  pub fn is_vip(&self, user_id: &str) -> bool {
+    self.vips.contains_key(user_id)
+  }
+
+  pub fn moderators(&self) -> impl Iterator<Item = &User> {
+    self.moderators.values()
+  }
+
+  pub fn vips(&self) -> impl Iterator<Item = &User> {
+    self.vips.values()
+  }
+
+  pub fn handle_event(&mut self, event: &Event) {
+    let (broadcaster, change) = match event {
+      Event::ModeratorAdd(e) => (&e.from_broadcaster, Change::AddModerator(&e.user)),
+      Event::ModeratorRemove(e) => (&e.broadcaster, Change::RemoveModerator(&e.user)),
+      Event::VIPAdd(e) => (&e.broadcaster, Change::AddVip(&e.user)),
+      Event::VIPRemove(e) => (&e.broadcaster, Change::RemoveVip(&e.user)),
+      Event::Moderate(e) => match &e.action {
+        Action::Mod { new_mod } => (&e.broadcaster, Change::AddModerator(&new_mod.user)),
+        Action::Unmod { unmod } => (&e.broadcaster, Change::RemoveModerator(&unmod.user)),
+        Action::Vip { vip } => (&e.broadcaster, Change::AddVip(&vip.user)),
+        Action::Unvip { unvip } => (&e.broadcaster, Change::RemoveVip(&unvip.user)),
+        _ => return,
+      },
+      _ => return,
+    };
+
+    if broadcaster.user_id != self.broadcaster_id {
+      return;
+    }
+
+    match change {
+      Change::AddModerator(user) => self.moderators.insert(user.user_id.clone(), user.clone()),
+      Change::RemoveModerator(user) => self.moderators.remove(&user.user_id),
+      Change::AddVip(user) => self.vips.insert(user.user_id.clone(), user.clone()),
+      Change::RemoveVip(user) => self.vips.remove(&user.user_id),
+    };
+  }
+}
+
+enum Change<'a> {
+  AddModerator(&'a User),
+  RemoveModerator(&'a User),
+  AddVip(&'a User),
+  RemoveVip(&'a User),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::eventsub::events::notification;
+
+  fn user(id: &str) -> User {
+    User { user_id: id.to_owned(), user_login: id.to_owned(), user_name: id.to_owned() }
+  }
+
+  #[test]
+  fn channel_roster() {
+    let mut roster = ChannelRoster::new("1337".to_owned(), vec![user("1")], vec![user("2")]);
+
+    let event = r#"
+    {
+      "broadcaster_user_id": "1337",
+      "broadcaster_user_login": "cool_user",
+      "broadcaster_user_name": "Cool_User",
+      "user_id": "3",
+      "user_login": "mod_user",
+      "user_name": "Mod_User"
+    }"#;
+    roster.handle_event(&notification("channel.moderator.add", event));
+    assert!(roster.is_moderator("3"));
+
+    let event = r#"
+    {
+      "broadcaster_user_id": "1337",
+      "broadcaster_user_login": "cool_user",
+      "broadcaster_user_name": "Cool_User",
+      "source_broadcaster_user_id": "1337",
+      "source_broadcaster_user_login": "cool_user",
+      "source_broadcaster_user_name": "Cool_User",
+      "moderator_user_id": "3",
+      "moderator_user_login": "mod_user",
+      "moderator_user_name": "Mod_User",
+      "action": "unvip",
+      "unvip": {
+        "user_id": "2",
+        "user_login": "2",
+        "user_name": "2"
+      }
+    }"#;
+    roster.handle_event(&notification("channel.moderate", event));
+    assert!(!roster.is_vip("2"));
+
+    let event = r#"
+    {
+      "broadcaster_user_id": "4242",
+      "broadcaster_user_login": "other_user",
+      "broadcaster_user_name": "Other_User",
+      "user_id": "1",
+      "user_login": "1",
+      "user_name": "1"
+    }"#;
+    roster.handle_event(&notification("channel.moderator.remove", event));
+    assert!(roster.is_moderator("1"));
+  }
+
+  #[test]
+  fn get_moderated_channels() {
+    let response = r#"
+    {
+      "broadcaster_id": "12345",
+      "broadcaster_login": "grateful_broadcaster",
+      "broadcaster_name": "Grateful_Broadcaster"
+    }"#;
+    let channel = serde_json::from_str::<ModeratedChannel>(response).unwrap();
+    assert_eq!(channel.broadcaster.user_login, "grateful_broadcaster");
+  }
+}
@@ -0,0 +1,283 @@
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, APIError, Method, broadcaster};
+use crate::{Twitch, TwitchError, User, maybe_null};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Channels without any segments get an empty schedule.
+  ///
+  /// No scope is required.
+  pub fn get_channel_stream_schedule(
+    &self,
+    broadcaster_id: &str,
+    start_time: Option<&str>,
+  ) -> Result<Schedule, TwitchError> {
+    let mut query = vec![("broadcaster_id", broadcaster_id), ("first", "25")];
+    query.extend(start_time.map(|time| ("start_time", time)));
+
+    let (mut schedule, mut cursor) =
+      match self.api.get_page::<Schedule>(APIEndpoint::Schedule, &query, None) {
+        // Twitch answers with not found when the channel has no segments, which is only an error
+        // when the channel itself doesn't exist.
+        Err(APIError::NotFound(message)) => {
+          return match self.get_users(&[broadcaster_id], &[])?.into_iter().next() {
+            Some(user) =>
+              Ok(Schedule { broadcaster: user.user, segments: vec![], vacation: None }),
+            None => Err(APIError::NotFound(message).into()),
+          };
+        }
+        page => page?,
+      };
+    while let Some(after) = cursor {
+      let (mut page, next) =
+        self.api.get_page::<Schedule>(APIEndpoint::Schedule, &query, Some(&after))?;
+      schedule.segments.append(&mut page.segments);
+      cursor = next;
+    }
+
+    Ok(schedule)
+  }
+
+  /// Returns the schedule as an iCalendar (RFC 5545) document.
+  ///
+  /// No scope is required.
+  pub fn get_channel_icalendar(&self, broadcaster_id: &str) -> Result<String, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id)];
+
+    Ok(self.api.request(Method::Get, APIEndpoint::ScheduleICalendar, &query).text()?)
+  }
+
+  /// Requires `Scope::ChannelManageSchedule`. Passing `None` disables vacation mode.
+  pub fn update_schedule_vacation(
+    &self,
+    broadcaster_id: &str,
+    vacation: Option<&Vacation>,
+  ) -> Result<(), TwitchError> {
+    let mut query = vec![
+      ("broadcaster_id", broadcaster_id),
+      ("is_vacation_enabled", if vacation.is_some() { "true" } else { "false" }),
+    ];
+    if let Some(vacation) = vacation {
+      query.extend([
+        ("vacation_start_time", vacation.start_time.as_str()),
+        ("vacation_end_time", &vacation.end_time),
+        ("timezone", vacation.timezone.as_deref().unwrap_or("UTC")),
+      ]);
+    }
+
+    Ok(self.api.request(Method::Patch, APIEndpoint::ScheduleSettings, &query).empty()?)
+  }
+
+  /// Requires `Scope::ChannelManageSchedule`.
+  pub fn create_schedule_segment(
+    &self,
+    broadcaster_id: &str,
+    segment: &SegmentBuilder,
+  ) -> Result<Schedule, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id)];
+
+    Ok(
+      self
+        .api
+        .request(Method::Post, APIEndpoint::ScheduleSegment, &query)
+        .json(segment)
+        .object()?,
+    )
+  }
+
+  /// Requires `Scope::ChannelManageSchedule`.
+  pub fn update_schedule_segment(
+    &self,
+    broadcaster_id: &str,
+    segment_id: &str,
+    segment: &SegmentBuilder,
+  ) -> Result<Schedule, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("id", segment_id)];
+
+    Ok(
+      self
+        .api
+        .request(Method::Patch, APIEndpoint::ScheduleSegment, &query)
+        .json(segment)
+        .object()?,
+    )
+  }
+
+  /// Requires `Scope::ChannelManageSchedule`.
+  pub fn delete_schedule_segment(
+    &self,
+    broadcaster_id: &str,
+    segment_id: &str,
+  ) -> Result<(), TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("id", segment_id)];
+
+    Ok(self.api.request(Method::Delete, APIEndpoint::ScheduleSegment, &query).empty()?)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Schedule {
+  #[serde(flatten, with = "broadcaster")]
+  pub broadcaster: User,
+  #[serde(deserialize_with = "maybe_null", default)]
+  pub segments:    Vec<Segment>,
+  pub vacation:    Option<Vacation>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Segment {
+  pub id:             String,
+  pub start_time:     String,
+  pub end_time:       String,
+  pub title:          String,
+  pub canceled_until: Option<String>,
+  pub category:       Option<Category>,
+  pub is_recurring:   bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Category {
+  pub id:   String,
+  pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Vacation {
+  pub start_time: String,
+  pub end_time:   String,
+  /// IANA name of the timezone used when enabling vacation mode, twitch only returns UTC times
+  /// so this is `None` in fetched schedules and UTC is assumed.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub timezone:   Option<String>,
+}
+
+impl Vacation {
+  /// The times are RFC3339 timestamps, the timezone an IANA name like `America/New_York`.
+  pub fn new(
+    start_time: impl Into<String>,
+    end_time: impl Into<String>,
+    timezone: impl Into<String>,
+  ) -> Self {
+    Self {
+      start_time: start_time.into(),
+      end_time:   end_time.into(),
+      timezone:   Some(timezone.into()),
+    }
+  }
+}
+
+/// Settings of a schedule segment, used both for creating segments and for partially updating
+/// them. Creating a segment requires a start time, timezone and duration.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct SegmentBuilder {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  start_time:   Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  timezone:     Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  duration:     Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  is_recurring: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  category_id:  Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  title:        Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  is_canceled:  Option<bool>,
+}
+
+impl SegmentBuilder {
+  /// The start time is an RFC3339 timestamp, the timezone an IANA name like `America/New_York`.
+  pub fn new(start_time: impl Into<String>, timezone: impl Into<String>, minutes: u32) -> Self {
+    Self::default().start_time(start_time).timezone(timezone).duration(minutes)
+  }
+
+  pub fn start_time(mut self, start_time: impl Into<String>) -> Self {
+    self.start_time = Some(start_time.into());
+    self
+  }
+
+  pub fn timezone(mut self, timezone: impl Into<String>) -> Self {
+    self.timezone = Some(timezone.into());
+    self
+  }
+
+  pub fn duration(mut self, minutes: u32) -> Self {
+    self.duration = Some(minutes.to_string());
+    self
+  }
+
+  /// Can only be set when creating a segment.
+  pub fn recurring(mut self, is_recurring: bool) -> Self {
+    self.is_recurring = Some(is_recurring);
+    self
+  }
+
+  pub fn category_id(mut self, category_id: impl Into<String>) -> Self {
+    self.category_id = Some(category_id.into());
+    self
+  }
+
+  pub fn title(mut self, title: impl Into<String>) -> Self {
+    self.title = Some(title.into());
+    self
+  }
+
+  /// Can only be set when updating a segment.
+  pub fn canceled(mut self, is_canceled: bool) -> Self {
+    self.is_canceled = Some(is_canceled);
+    self
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn get_channel_stream_schedule() {
+    let response = r#"
+    {
+      "segments": [
+        {
+          "id": "eyJzZWdtZW50SUQiOiJlNGFjYzcyNC0zNzFmLTQwMmMtODFjYS0yM2FkYTc5NzU5ZDQiLCJpc29ZZWFyIjoyMDIxLCJpc29XZWVrIjoyNn0=",
+          "start_time": "2021-07-01T18:00:00Z",
+          "end_time": "2021-07-01T19:00:00Z",
+          "title": "TwitchDev Monthly Update // July 1, 2021",
+          "canceled_until": null,
+          "category": {
+            "id": "509670",
+            "name": "Science & Technology"
+          },
+          "is_recurring": false
+        }
+      ],
+      "broadcaster_id": "141981764",
+      "broadcaster_name": "TwitchDev",
+      "broadcaster_login": "twitchdev",
+      "vacation": {
+        "start_time": "2021-08-01T00:00:00Z",
+        "end_time": "2021-08-08T00:00:00Z"
+      }
+    }"#;
+    let schedule = serde_json::from_str::<Schedule>(response).unwrap();
+    assert_eq!(schedule.segments.len(), 1);
+    assert_eq!(schedule.vacation.unwrap().timezone, None);
+  }
+
+  #[test]
+  fn segment_builder() {
+    let segment =
+      SegmentBuilder::new("2021-07-01T18:00:00Z", "America/New_York", 60).recurring(false);
+    let segment = serde_json::to_value(&segment).unwrap();
+    assert_eq!(segment["duration"], "60");
+    assert_eq!(segment["is_recurring"], false);
+    assert!(segment.get("title").is_none());
+  }
+}
@@ -0,0 +1,255 @@
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, APIError, Method};
+use crate::{StreamType, Twitch, TwitchError, User};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Fetches matching streams up to [`StreamFilter::max_results`]. The filter needs at least one
+  /// user, game or language, otherwise this would walk every live stream on twitch. Users are
+  /// looked up in requests of at most 100 users.
+  ///
+  /// No scope is required.
+  pub fn get_streams(&self, filter: &StreamFilter) -> Result<Vec<Stream>, TwitchError> {
+    if filter.is_empty() {
+      let message = "at least one user, game or language is required".to_owned();
+      return Err(APIError::InvalidRequest(message).into());
+    }
+
+    let stream_type = if filter.live_only { "live" } else { "all" };
+    let mut query = vec![("type", stream_type)];
+    query.extend(filter.game_ids.iter().map(|id| ("game_id", id.as_str())));
+    query.extend(filter.languages.iter().map(|language| ("language", language.as_str())));
+
+    let users: Vec<_> = filter
+      .user_ids
+      .iter()
+      .map(|id| ("user_id", id.as_str()))
+      .chain(filter.user_logins.iter().map(|login| ("user_login", login.as_str())))
+      .collect();
+    let user_chunks: Vec<_> =
+      if users.is_empty() { vec![&[][..]] } else { users.chunks(100).collect() };
+
+    // Every user has at most one stream, so a long user list is never cut short by the default.
+    let max_results = filter.max_results.unwrap_or(DEFAULT_MAX_STREAMS.max(users.len()));
+    let mut streams = Vec::new();
+    for users in user_chunks {
+      if streams.len() >= max_results {
+        break;
+      }
+      let query: Vec<_> = query.iter().chain(users).copied().collect();
+      streams.extend(self.api.get_limited(
+        APIEndpoint::Streams,
+        &query,
+        max_results - streams.len(),
+      )?);
+    }
+
+    Ok(streams)
+  }
+
+  /// Requires `Scope::UserReadFollows`.
+  pub fn get_followed_streams(&self, user_id: &str) -> Result<Vec<Stream>, TwitchError> {
+    let query = [("user_id", user_id), ("first", "100")];
+
+    Ok(self.api.get(APIEndpoint::FollowedStreams, &query)?)
+  }
+
+  /// Requires `Scope::ChannelManageBroadcast`.
+  pub fn create_stream_marker(
+    &self,
+    user_id: &str,
+    description: Option<&str>,
+  ) -> Result<StreamMarker, TwitchError> {
+    let request = CreateStreamMarker { user_id, description };
+
+    Ok(self.api.request(Method::Post, APIEndpoint::StreamMarkers, &[]).json(request).one()?)
+  }
+
+  /// Requires `Scope::UserReadBroadcast` or `Scope::ChannelManageBroadcast`.
+  pub fn get_stream_markers(&self, user_id: &str) -> Result<Vec<StreamMarkers>, TwitchError> {
+    let query = [("user_id", user_id), ("first", "100")];
+
+    Ok(self.api.get(APIEndpoint::StreamMarkers, &query)?)
+  }
+
+  /// Requires `Scope::UserReadBroadcast` or `Scope::ChannelManageBroadcast`.
+  pub fn get_video_stream_markers(
+    &self,
+    video_id: &str,
+  ) -> Result<Vec<StreamMarkers>, TwitchError> {
+    let query = [("video_id", video_id), ("first", "100")];
+
+    Ok(self.api.get(APIEndpoint::StreamMarkers, &query)?)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const DEFAULT_MAX_STREAMS: usize = 100;
+
+#[derive(Debug, Clone, Default)]
+pub struct StreamFilter {
+  user_ids:    Vec<String>,
+  user_logins: Vec<String>,
+  game_ids:    Vec<String>,
+  languages:   Vec<String>,
+  live_only:   bool,
+  max_results: Option<usize>,
+}
+
+impl StreamFilter {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn user_id(mut self, user_id: impl Into<String>) -> Self {
+    self.user_ids.push(user_id.into());
+    self
+  }
+
+  pub fn user_login(mut self, user_login: impl Into<String>) -> Self {
+    self.user_logins.push(user_login.into());
+    self
+  }
+
+  pub fn game_id(mut self, game_id: impl Into<String>) -> Self {
+    self.game_ids.push(game_id.into());
+    self
+  }
+
+  pub fn language(mut self, language: impl Into<String>) -> Self {
+    self.languages.push(language.into());
+    self
+  }
+
+  pub fn live_only(mut self, live_only: bool) -> Self {
+    self.live_only = live_only;
+    self
+  }
+
+  /// Stops fetching streams after this many, defaults to 100 or the number of users if more users
+  /// were added.
+  pub fn max_results(mut self, max_results: usize) -> Self {
+    self.max_results = Some(max_results);
+    self
+  }
+
+  fn is_empty(&self) -> bool {
+    self.user_ids.is_empty()
+      && self.user_logins.is_empty()
+      && self.game_ids.is_empty()
+      && self.languages.is_empty()
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Stream {
+  pub id:            String,
+  #[serde(flatten)]
+  pub user:          User,
+  pub game_id:       String,
+  pub game_name:     String,
+  #[serde(rename = "type")]
+  pub stream_type:   StreamType,
+  pub title:         String,
+  pub tags:          Vec<String>,
+  pub viewer_count:  u64,
+  pub started_at:    String,
+  pub language:      String,
+  pub thumbnail_url: String,
+  pub is_mature:     bool,
+}
+
+#[derive(Serialize, Debug)]
+struct CreateStreamMarker<'a> {
+  user_id:     &'a str,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  description: Option<&'a str>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StreamMarker {
+  pub id:               String,
+  pub created_at:       String,
+  pub description:      String,
+  pub position_seconds: u64,
+  pub url:              Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StreamMarkers {
+  #[serde(flatten)]
+  pub user:   User,
+  pub videos: Vec<VideoMarkers>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VideoMarkers {
+  pub video_id: String,
+  pub markers:  Vec<StreamMarker>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn get_streams() {
+    let response = r#"
+    {
+      "id": "123456789",
+      "user_id": "98765",
+      "user_login": "sandysanderman",
+      "user_name": "SandySanderman",
+      "game_id": "494131",
+      "game_name": "Little Nightmares",
+      "type": "live",
+      "title": "hablamos y le damos a Little Nightmares 1",
+      "tags": ["Español"],
+      "viewer_count": 78365,
+      "started_at": "2021-03-10T15:04:21Z",
+      "language": "es",
+      "thumbnail_url": "https://static-cdn.jtvnw.net/previews-ttv/live_user_auronplay-{width}x{height}.jpg",
+      "tag_ids": [],
+      "is_mature": false
+    }"#;
+    let stream = serde_json::from_str::<Stream>(response).unwrap();
+    assert!(matches!(stream.stream_type, StreamType::Live));
+  }
+
+  #[test]
+  fn get_streams_without_filter() {
+    let twitch = Twitch::new(String::new(), String::new());
+    let result = twitch.get_streams(&StreamFilter::new().live_only(true));
+    assert!(matches!(result, Err(TwitchError::APIError(APIError::InvalidRequest(_)))));
+  }
+
+  #[test]
+  fn get_stream_markers() {
+    let response = r#"
+    {
+      "user_id": "123",
+      "user_name": "TwitchName",
+      "user_login": "twitchname",
+      "videos": [
+        {
+          "video_id": "456",
+          "markers": [
+            {
+              "id": "106b8d6243a4f883d25ad75e6cdffdc4",
+              "created_at": "2018-08-20T20:10:03Z",
+              "description": "hello, this is a marker!",
+              "position_seconds": 244,
+              "url": "https://twitch.tv/videos/456?t=0h4m06s"
+            }
+          ]
+        }
+      ]
+    }"#;
+    serde_json::from_str::<StreamMarkers>(response).unwrap();
+  }
+}
@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, APIError, Method, broadcaster, gifter};
+use crate::{Event, Twitch, TwitchError, User};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Requires `Scope::ChannelReadSubscriptions`.
+  pub fn get_broadcaster_subscriptions(
+    &self,
+    broadcaster_id: &str,
+    user_ids: &[&str],
+  ) -> Result<BroadcasterSubscriptions, TwitchError> {
+    let mut query = vec![("broadcaster_id", broadcaster_id), ("first", "100")];
+    query.extend(user_ids.iter().map(|&id| ("user_id", id)));
+
+    let (subscriptions, totals) = self
+      .api
+      .get_with_totals::<_, SubscriptionTotals>(APIEndpoint::BroadcasterSubscriptions, &query)?;
+
+    Ok(BroadcasterSubscriptions { subscriptions, total: totals.total, points: totals.points })
+  }
+
+  /// Requires `Scope::UserReadSubscriptions`, returns `None` if the user isn't subscribed.
+  pub fn check_user_subscription(
+    &self,
+    broadcaster_id: &str,
+    user_id: &str,
+  ) -> Result<Option<UserSubscription>, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("user_id", user_id)];
+
+    match self.api.request(Method::Get, APIEndpoint::UserSubscription, &query).one() {
+      Ok(subscription) => Ok(Some(subscription)),
+      Err(APIError::NotFound(_)) => Ok(None),
+      Err(error) => Err(error.into()),
+    }
+  }
+
+  /// Requires `Scope::ChannelReadSubscriptions`.
+  pub fn get_subscriber_roster(
+    &self,
+    broadcaster_id: &str,
+  ) -> Result<SubscriberRoster, TwitchError> {
+    let subscriptions = self.get_broadcaster_subscriptions(broadcaster_id, &[])?;
+
+    Ok(SubscriberRoster::new(broadcaster_id.to_owned(), subscriptions.subscriptions))
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Deserialize, Debug)]
+struct SubscriptionTotals {
+  total:  u64,
+  points: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BroadcasterSubscriptions {
+  pub subscriptions: Vec<Subscriber>,
+  pub total:         u64,
+  pub points:        u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Subscriber {
+  #[serde(flatten, with = "broadcaster")]
+  pub broadcaster: User,
+  #[serde(flatten)]
+  pub user:        User,
+  #[serde(flatten, with = "gifter::option")]
+  pub gifter:      Option<User>,
+  pub is_gift:     bool,
+  pub plan_name:   String,
+  pub tier:        String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UserSubscription {
+  #[serde(flatten, with = "broadcaster")]
+  pub broadcaster: User,
+  #[serde(flatten, with = "gifter::option")]
+  pub gifter:      Option<User>,
+  pub is_gift:     bool,
+  pub tier:        String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Subscribers of a single channel by user id and their tier, kept up to date by feeding it eventsub
+/// events.
+#[derive(Debug, Clone)]
+pub struct SubscriberRoster {
+  broadcaster_id: String,
+  subscribers:    HashMap<String, RosterEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RosterEntry {
+  pub user:    User,
+  pub tier:    String,
+  pub is_gift: bool,
+}
+
+impl SubscriberRoster {
+  pub fn new(broadcaster_id: String, subscribers: Vec<Subscriber>) -> Self {
+    let subscribers = subscribers
+      .into_iter()
+      .map(|s| {
+        let entry = RosterEntry { user: s.user, tier: s.tier, is_gift: s.is_gift };
+        (entry.user.user_id.clone(), entry)
+      })
+      .collect();
+
+    Self { broadcaster_id, subscribers }
+  }
+
+  pub fn broadcaster_id(&self) -> &str {
+    &self.broadcaster_id
+  }
+
+  pub fn is_subscribed(&self, user_id: &str) -> bool {
+    self.subscribers.contains_key(user_id)
+  }
+
+  pub fn get(&self, user_id: &str) -> Option<&RosterEntry> {
+    self.subscribers.get(user_id)
+  }
+
+  pub fn subscribers(&self) -> impl Iterator<Item = &RosterEntry> {
+    self.subscribers.values()
+  }
+
+  pub fn len(&self) -> usize {
+    self.subscribers.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.subscribers.is_empty()
+  }
+
+  /// Subscriber points as counted by twitch, 1 for tier 1, 2 for tier 2 and 6 for tier 3.
+  pub fn points(&self) -> u64 {
+    let points = |tier: &str| match tier {
+      "2000" => 2,
+      "3000" => 6,
+      _ => 1,
+    };
+    self.subscribers.values().map(|s| points(&s.tier)).sum()
+  }
+
+  /// Gift events are ignored, the recipients are only known from the `Subscribe` events sent for
+  /// each of them.
+  pub fn handle_event(&mut self, event: &Event) {
+    let (broadcaster, change) = match event {
+      Event::Subscribe(e) => (&e.broadcaster, Change::Add(&e.user, &e.tier, e.is_gift)),
+      Event::SubscriptionMessage(e) => (&e.broadcaster, Change::Add(&e.user, &e.tier, false)),
+      Event::SubscriptionEnd(e) => (&e.broadcaster, Change::Remove(&e.user)),
+      _ => return,
+    };
+
+    if broadcaster.user_id != self.broadcaster_id {
+      return;
+    }
+
+    match change {
+      Change::Add(user, tier, is_gift) => {
+        let entry = RosterEntry { user: user.clone(), tier: tier.to_owned(), is_gift };
+        self.subscribers.insert(user.user_id.clone(), entry);
+      }
+      Change::Remove(user) => {
+        self.subscribers.remove(&user.user_id);
+      }
+    }
+  }
+}
+
+enum Change<'a> {
+  Add(&'a User, &'a str, bool),
+  Remove(&'a User),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::super::PagedList;
+  use super::*;
+  use crate::eventsub::events::notification;
+
+  #[test]
+  fn get_broadcaster_subscriptions() {
+    let response = r#"
+    {
+      "data": [
+        {
+          "broadcaster_id": "141981764",
+          "broadcaster_login": "twitchdev",
+          "broadcaster_name": "TwitchDev",
+          "gifter_id": "12826",
+          "gifter_login": "twitch",
+          "gifter_name": "Twitch",
+          "is_gift": true,
+          "tier": "1000",
+          "plan_name": "Channel Subscription (twitchdev)",
+          "user_id": "527115020",
+          "user_name": "twitchgaming",
+          "user_login": "twitchgaming"
+        },
+        {
+          "broadcaster_id": "141981764",
+          "broadcaster_login": "twitchdev",
+          "broadcaster_name": "TwitchDev",
+          "gifter_id": "",
+          "gifter_login": "",
+          "gifter_name": "",
+          "is_gift": false,
+          "tier": "3000",
+          "plan_name": "Channel Subscription (twitchdev)",
+          "user_id": "1337",
+          "user_name": "Cool_User",
+          "user_login": "cool_user"
+        }
+      ],
+      "pagination": {
+        "cursor": "xxxx"
+      },
+      "total": 13,
+      "points": 13
+    }"#;
+    let page = serde_json::from_str::<PagedList<Subscriber, SubscriptionTotals>>(response).unwrap();
+    assert_eq!(page.totals.total, 13);
+    assert!(page.data[0].gifter.is_some());
+    assert!(page.data[1].gifter.is_none());
+  }
+
+  #[test]
+  fn subscriber_roster() {
+    let subscribers = r#"
+    [
+      {
+        "broadcaster_id": "141981764",
+        "broadcaster_login": "twitchdev",
+        "broadcaster_name": "TwitchDev",
+        "gifter_id": "12826",
+        "gifter_login": "twitch",
+        "gifter_name": "Twitch",
+        "is_gift": true,
+        "tier": "1000",
+        "plan_name": "Channel Subscription (twitchdev)",
+        "user_id": "527115020",
+        "user_name": "twitchgaming",
+        "user_login": "twitchgaming"
+      },
+      {
+        "broadcaster_id": "141981764",
+        "broadcaster_login": "twitchdev",
+        "broadcaster_name": "TwitchDev",
+        "gifter_id": "",
+        "gifter_login": "",
+        "gifter_name": "",
+        "is_gift": false,
+        "tier": "3000",
+        "plan_name": "Channel Subscription (twitchdev)",
+        "user_id": "1337",
+        "user_name": "Cool_User",
+        "user_login": "cool_user"
+      }
+    ]"#;
+    let mut roster =
+      SubscriberRoster::new("141981764".to_owned(), serde_json::from_str(subscribers).unwrap());
+    assert_eq!(roster.points(), 7);
+
+    let gift = r#"
+    {
+      "user_id": "1234",
+      "user_login": "cool_user",
+      "user_name": "Cool_User",
+      "broadcaster_user_id": "141981764",
+      "broadcaster_user_login": "twitchdev",
+      "broadcaster_user_name": "TwitchDev",
+      "total": 2,
+      "tier": "1000",
+      "cumulative_total": 284,
+      "is_anonymous": false
+    }"#;
+    roster.handle_event(&notification("channel.subscription.gift", gift));
+    assert_eq!(roster.len(), 2);
+
+    let event = r#"
+    {
+      "user_id": "1337",
+      "user_login": "cool_user",
+      "user_name": "Cool_User",
+      "broadcaster_user_id": "141981764",
+      "broadcaster_user_login": "twitchdev",
+      "broadcaster_user_name": "TwitchDev",
+      "tier": "3000",
+      "is_gift": false
+    }"#;
+    roster.handle_event(&notification("channel.subscribe", &event.replace("1337", "9001")));
+    assert!(roster.is_subscribed("9001"));
+    assert!(!roster.get("9001").unwrap().is_gift);
+
+    let other_channel = event.replace("141981764", "4242");
+    roster.handle_event(&notification("channel.subscription.end", &other_channel));
+    assert!(roster.is_subscribed("1337"));
+
+    roster.handle_event(&notification("channel.subscription.end", event));
+    assert!(!roster.is_subscribed("1337"));
+    assert_eq!(roster.len(), 2);
+  }
+
+  #[test]
+  fn check_user_subscription() {
+    let response = r#"
+    {
+      "broadcaster_id": "141981764",
+      "broadcaster_name": "TwitchDev",
+      "broadcaster_login": "twitchdev",
+      "is_gift": false,
+      "tier": "1000"
+    }"#;
+    let subscription = serde_json::from_str::<UserSubscription>(response).unwrap();
+    assert!(subscription.gifter.is_none());
+  }
+}
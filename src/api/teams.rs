@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, Method, broadcaster};
+use crate::{Twitch, TwitchError, User, maybe_null};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// No scope is required.
+  pub fn get_team_by_name(&self, name: &str) -> Result<Team, TwitchError> {
+    let query = [("name", name)];
+
+    Ok(self.api.request(Method::Get, APIEndpoint::Teams, &query).one()?)
+  }
+
+  /// No scope is required.
+  pub fn get_team_by_id(&self, team_id: &str) -> Result<Team, TwitchError> {
+    let query = [("id", team_id)];
+
+    Ok(self.api.request(Method::Get, APIEndpoint::Teams, &query).one()?)
+  }
+
+  /// No scope is required.
+  pub fn get_channel_teams(&self, broadcaster_id: &str) -> Result<Vec<ChannelTeam>, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id)];
+
+    Ok(self.api.request(Method::Get, APIEndpoint::ChannelTeams, &query).list()?)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Team {
+  pub id:                   String,
+  pub team_name:            String,
+  pub team_display_name:    String,
+  pub info:                 String,
+  pub thumbnail_url:        String,
+  pub background_image_url: Option<String>,
+  pub banner:               Option<String>,
+  pub created_at:           String,
+  pub updated_at:           String,
+  #[serde(deserialize_with = "maybe_null", default)]
+  pub users:                Vec<User>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChannelTeam {
+  #[serde(flatten, with = "broadcaster")]
+  pub broadcaster:          User,
+  pub id:                   String,
+  pub team_name:            String,
+  pub team_display_name:    String,
+  pub info:                 String,
+  pub thumbnail_url:        String,
+  pub background_image_url: Option<String>,
+  pub banner:               Option<String>,
+  pub created_at:           String,
+  pub updated_at:           String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn get_teams() {
+    let response = r#"
+    {
+      "users": [
+        {
+          "user_id": "278217731",
+          "user_name": "mastermndio",
+          "user_login": "mastermndio"
+        },
+        {
+          "user_id": "41284990",
+          "user_name": "jenninexus",
+          "user_login": "jenninexus"
+        }
+      ],
+      "background_image_url": null,
+      "banner": null,
+      "created_at": "2019-10-11T19:54:22Z",
+      "updated_at": "2020-11-18T15:56:41Z",
+      "info": "<p>An inclusive community of live coders.</p>",
+      "thumbnail_url": "https://static-cdn.jtvnw.net/jtv_user_pictures/team-livecoders-team_logo_image-bf1d9a87ca81432687de60e24ad9593d-600x600.png",
+      "team_name": "livecoders",
+      "team_display_name": "Live Coders",
+      "id": "6358"
+    }"#;
+    let team = serde_json::from_str::<Team>(response).unwrap();
+    assert_eq!(team.users.len(), 2);
+  }
+
+  #[test]
+  fn get_channel_teams() {
+    let response = r#"
+    {
+      "broadcaster_id": "96909659",
+      "broadcaster_name": "CSharpFritz",
+      "broadcaster_login": "csharpfritz",
+      "background_image_url": null,
+      "banner": null,
+      "created_at": "2019-02-11T12:09:22Z",
+      "updated_at": "2020-11-18T15:56:41Z",
+      "info": "<p>An inclusive community of live coders.</p>",
+      "thumbnail_url": "https://static-cdn.jtvnw.net/jtv_user_pictures/team-livecoders-team_logo_image-bf1d9a87ca81432687de60e24ad9593d-600x600.png",
+      "team_name": "livecoders",
+      "team_display_name": "Live Coders",
+      "id": "6358"
+    }"#;
+    let team = serde_json::from_str::<ChannelTeam>(response).unwrap();
+    assert_eq!(team.broadcaster.user_login, "csharpfritz");
+  }
+}
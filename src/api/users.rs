@@ -0,0 +1,229 @@
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, Method, account, blocked_user, query_value};
+use crate::{Twitch, TwitchError, User};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Looks up users by id and login, splitting the lookup into requests of at most 100 users.
+  ///
+  /// No scope is required.
+  pub fn get_users(
+    &self,
+    user_ids: &[&str],
+    logins: &[&str],
+  ) -> Result<Vec<UserInfo>, TwitchError> {
+    let lookups: Vec<_> = user_ids
+      .iter()
+      .map(|&id| ("id", id))
+      .chain(logins.iter().map(|&login| ("login", login)))
+      .collect();
+
+    let mut users = Vec::new();
+    for query in lookups.chunks(100) {
+      users.extend(self.api.request(Method::Get, APIEndpoint::Users, query).list()?);
+    }
+
+    Ok(users)
+  }
+
+  /// No scope is required.
+  pub fn get_user_by_login(&self, login: &str) -> Result<Option<UserInfo>, TwitchError> {
+    Ok(self.get_users(&[], &[login])?.into_iter().next())
+  }
+
+  /// Updates the description of the user the token belongs to.
+  ///
+  /// Requires `Scope::UserEdit`.
+  pub fn update_user_description(&self, description: &str) -> Result<UserInfo, TwitchError> {
+    let query = [("description", description)];
+
+    Ok(self.api.request(Method::Put, APIEndpoint::Users, &query).one()?)
+  }
+
+  /// Requires `Scope::UserReadBlockedUsers`.
+  pub fn get_user_block_list(&self, broadcaster_id: &str) -> Result<Vec<User>, TwitchError> {
+    let query = [("broadcaster_id", broadcaster_id), ("first", "100")];
+    let blocks: Vec<BlockedUser> = self.api.get(APIEndpoint::UserBlocks, &query)?;
+
+    Ok(blocks.into_iter().map(|block| block.user).collect())
+  }
+
+  /// Requires `Scope::UserManageBlockedUsers`.
+  pub fn block_user(
+    &self,
+    target_user_id: &str,
+    source_context: Option<BlockSourceContext>,
+    reason: Option<BlockReason>,
+  ) -> Result<(), TwitchError> {
+    let source_context = source_context.map(|context| query_value(&context));
+    let reason = reason.map(|reason| query_value(&reason));
+    let mut query = vec![("target_user_id", target_user_id)];
+    query.extend(source_context.as_deref().map(|context| ("source_context", context)));
+    query.extend(reason.as_deref().map(|reason| ("reason", reason)));
+
+    Ok(self.api.request(Method::Put, APIEndpoint::UserBlocks, &query).empty()?)
+  }
+
+  /// Requires `Scope::UserManageBlockedUsers`.
+  pub fn unblock_user(&self, target_user_id: &str) -> Result<(), TwitchError> {
+    let query = [("target_user_id", target_user_id)];
+
+    Ok(self.api.request(Method::Delete, APIEndpoint::UserBlocks, &query).empty()?)
+  }
+
+  /// Looks up chat colors in requests of at most 100 users, users that never set a color have an
+  /// empty color.
+  ///
+  /// No scope is required.
+  pub fn get_user_chat_color(&self, user_ids: &[&str]) -> Result<Vec<UserChatColor>, TwitchError> {
+    let lookups: Vec<_> = user_ids.iter().map(|&id| ("user_id", id)).collect();
+
+    let mut colors = Vec::new();
+    for query in lookups.chunks(100) {
+      colors.extend(self.api.request(Method::Get, APIEndpoint::ChatColor, query).list()?);
+    }
+
+    Ok(colors)
+  }
+
+  /// Requires `Scope::UserManageChatColor`. Hex colors are only available to Turbo and
+  /// Prime users.
+  pub fn update_user_chat_color(
+    &self,
+    user_id: &str,
+    color: &ChatColor,
+  ) -> Result<(), TwitchError> {
+    let color = query_value(color);
+    let query = [("user_id", user_id), ("color", &color)];
+
+    Ok(self.api.request(Method::Put, APIEndpoint::ChatColor, &query).empty()?)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UserInfo {
+  #[serde(flatten, with = "account")]
+  pub user:              User,
+  #[serde(rename = "type")]
+  pub user_type:         String,
+  pub broadcaster_type:  String,
+  pub description:       String,
+  pub profile_image_url: String,
+  pub offline_image_url: String,
+  pub email:             Option<String>,
+  pub created_at:        String,
+}
+
+#[derive(Deserialize, Debug)]
+struct BlockedUser {
+  #[serde(flatten, with = "blocked_user")]
+  user: User,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BlockSourceContext {
+  Chat,
+  Whisper,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BlockReason {
+  Harassment,
+  Spam,
+  Other,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UserChatColor {
+  #[serde(flatten)]
+  pub user:  User,
+  pub color: String,
+}
+
+/// The named colors available to every user, or a hex color like `#9146FF`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChatColor {
+  Blue,
+  BlueViolet,
+  CadetBlue,
+  Chocolate,
+  Coral,
+  DodgerBlue,
+  Firebrick,
+  GoldenRod,
+  Green,
+  HotPink,
+  OrangeRed,
+  Red,
+  SeaGreen,
+  SpringGreen,
+  YellowGreen,
+  #[serde(untagged)]
+  Hex(String),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn get_users() {
+    let response = r#"
+    {
+      "id": "141981764",
+      "login": "twitchdev",
+      "display_name": "TwitchDev",
+      "type": "",
+      "broadcaster_type": "partner",
+      "description": "Supporting third-party developers building Twitch integrations from chatbots to game integrations.",
+      "profile_image_url": "https://static-cdn.jtvnw.net/jtv_user_pictures/8a6381c7-d0c0-4576-b179-38bd5ce1d6af-profile_image-300x300.png",
+      "offline_image_url": "https://static-cdn.jtvnw.net/jtv_user_pictures/3f13ab61-ec78-4fe6-8481-8682cb3b0ac2-channel_offline_image-1920x1080.png",
+      "view_count": 5980557,
+      "email": "not-real@email.com",
+      "created_at": "2016-12-14T20:32:28Z"
+    }"#;
+    let user = serde_json::from_str::<UserInfo>(response).unwrap();
+    assert_eq!(user.user.user_login, "twitchdev");
+    assert_eq!(user.user.user_name, "TwitchDev");
+  }
+
+  #[test]
+  fn get_user_block_list() {
+    let response = r#"
+    {
+      "user_id": "135093069",
+      "user_login": "bluelava",
+      "display_name": "BlueLava"
+    }"#;
+    let block = serde_json::from_str::<BlockedUser>(response).unwrap();
+    assert_eq!(block.user.user_name, "BlueLava");
+  }
+
+  #[test]
+  fn get_user_chat_color() {
+    let response = r##"
+    {
+      "user_id": "11111",
+      "user_name": "SpeedySpeedster1",
+      "user_login": "speedyspeedster1",
+      "color": "#9146FF"
+    }"##;
+    let color = serde_json::from_str::<UserChatColor>(response).unwrap();
+    assert_eq!(color.color, "#9146FF");
+  }
+
+  #[test]
+  fn chat_color_query() {
+    assert_eq!(query_value(&ChatColor::BlueViolet), "blue_violet");
+    assert_eq!(query_value(&ChatColor::Hex("#9146FF".to_owned())), "#9146FF");
+  }
+}
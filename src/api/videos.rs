@@ -0,0 +1,213 @@
+use serde::{Deserialize, Serialize};
+
+use super::{APIEndpoint, Method, query_value};
+use crate::{Twitch, TwitchError, User};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Twitch {
+  /// Videos of a user or game are fetched up to [`VideoFilter::max_results`].
+  ///
+  /// No scope is required.
+  pub fn get_videos(&self, filter: &VideoFilter) -> Result<Vec<Video>, TwitchError> {
+    let period = query_value(&filter.period);
+    let sort = query_value(&filter.sort);
+    let video_type = query_value(&filter.video_type);
+
+    let mut query: Vec<_> =
+      filter.source.iter().map(|(key, value)| (*key, value.as_str())).collect();
+    if filter.source.iter().any(|(key, _)| *key == "id") {
+      return Ok(self.api.request(Method::Get, APIEndpoint::Videos, &query).list()?);
+    }
+
+    query.extend([("period", period.as_str()), ("sort", &sort), ("type", &video_type)]);
+    query.extend(filter.language.as_deref().map(|language| ("language", language)));
+    let max_results = filter.max_results.unwrap_or(DEFAULT_MAX_VIDEOS);
+
+    Ok(self.api.get_limited(APIEndpoint::Videos, &query, max_results)?)
+  }
+
+  /// Looks for the archived VOD of a stream among the 100 newest archives of the broadcaster.
+  ///
+  /// No scope is required.
+  pub fn get_stream_archive(
+    &self,
+    user_id: &str,
+    stream_id: &str,
+  ) -> Result<Option<Video>, TwitchError> {
+    let query = [("user_id", user_id), ("type", "archive"), ("sort", "time"), ("first", "100")];
+    let videos: Vec<Video> = self.api.request(Method::Get, APIEndpoint::Videos, &query).list()?;
+
+    Ok(videos.into_iter().find(|video| video.stream_id.as_deref() == Some(stream_id)))
+  }
+
+  /// Requires `Scope::ChannelManageVideos`. Returns the ids of the deleted videos.
+  pub fn delete_videos(&self, video_ids: &[&str]) -> Result<Vec<String>, TwitchError> {
+    let mut deleted = Vec::new();
+    for ids in video_ids.chunks(5) {
+      let query: Vec<_> = ids.iter().map(|&id| ("id", id)).collect();
+      deleted.extend(self.api.request(Method::Delete, APIEndpoint::Videos, &query).list()?);
+    }
+
+    Ok(deleted)
+  }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const DEFAULT_MAX_VIDEOS: usize = 100;
+
+/// Selects videos of a user, of a game or by id. Period, sort and type only apply to user and game
+/// lookups, language only to game lookups.
+#[derive(Debug, Clone)]
+pub struct VideoFilter {
+  source:      Vec<(&'static str, String)>,
+  language:    Option<String>,
+  period:      VideoPeriod,
+  sort:        VideoSort,
+  video_type:  VideoType,
+  max_results: Option<usize>,
+}
+
+impl VideoFilter {
+  pub fn user(user_id: impl Into<String>) -> Self {
+    Self::from_source(vec![("user_id", user_id.into())])
+  }
+
+  pub fn game(game_id: impl Into<String>) -> Self {
+    Self::from_source(vec![("game_id", game_id.into())])
+  }
+
+  /// At most 100 videos may be requested by id.
+  pub fn ids<S: Into<String>>(video_ids: impl IntoIterator<Item = S>) -> Self {
+    Self::from_source(video_ids.into_iter().map(|id| ("id", id.into())).collect())
+  }
+
+  pub fn language(mut self, language: impl Into<String>) -> Self {
+    self.language = Some(language.into());
+    self
+  }
+
+  pub fn period(mut self, period: VideoPeriod) -> Self {
+    self.period = period;
+    self
+  }
+
+  pub fn sort(mut self, sort: VideoSort) -> Self {
+    self.sort = sort;
+    self
+  }
+
+  pub fn video_type(mut self, video_type: VideoType) -> Self {
+    self.video_type = video_type;
+    self
+  }
+
+  /// Stops fetching videos of a user or game after this many, defaults to 100.
+  pub fn max_results(mut self, max_results: usize) -> Self {
+    self.max_results = Some(max_results);
+    self
+  }
+
+  fn from_source(source: Vec<(&'static str, String)>) -> Self {
+    Self {
+      source,
+      language: None,
+      period: VideoPeriod::All,
+      sort: VideoSort::Time,
+      video_type: VideoType::All,
+      max_results: None,
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VideoPeriod {
+  All,
+  Day,
+  Month,
+  Week,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VideoSort {
+  Time,
+  Trending,
+  Views,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VideoType {
+  All,
+  Archive,
+  Highlight,
+  Upload,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Video {
+  pub id:             String,
+  pub stream_id:      Option<String>,
+  #[serde(flatten)]
+  pub user:           User,
+  pub title:          String,
+  pub description:    String,
+  pub created_at:     String,
+  pub published_at:   String,
+  pub url:            String,
+  pub thumbnail_url:  String,
+  pub viewable:       String,
+  pub view_count:     u64,
+  pub language:       String,
+  #[serde(rename = "type")]
+  pub video_type:     VideoType,
+  pub duration:       String,
+  pub muted_segments: Option<Vec<MutedSegment>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MutedSegment {
+  pub duration: u64,
+  pub offset:   u64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn get_videos() {
+    let response = r#"
+    {
+      "id": "335921245",
+      "stream_id": null,
+      "user_id": "141981764",
+      "user_login": "twitchdev",
+      "user_name": "TwitchDev",
+      "title": "Twitch Developers 101",
+      "description": "Welcome to Twitch development!",
+      "created_at": "2018-11-14T21:30:18Z",
+      "published_at": "2018-11-14T22:04:30Z",
+      "url": "https://www.twitch.tv/videos/335921245",
+      "thumbnail_url": "https://static-cdn.jtvnw.net/cf_vods/d2nvs31859zcd8/twitchdev/335921245/ce0f3a7f-57a3-4152-bc06-0c6610189fb3/thumb/index-0000000000-%{width}x%{height}.jpg",
+      "viewable": "public",
+      "view_count": 1863062,
+      "language": "en",
+      "type": "upload",
+      "duration": "3m21s",
+      "muted_segments": [
+        {
+          "duration": 30,
+          "offset": 120
+        }
+      ]
+    }"#;
+    let video = serde_json::from_str::<Video>(response).unwrap();
+    assert_eq!(video.video_type, VideoType::Upload);
+  }
+}
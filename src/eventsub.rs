@@ -19,7 +19,7 @@ use super::api::APIEndpoint;
 #[derive(Error, Debug)]
 pub enum EventsubError {
   #[error("failed to establish websocket connection: {0}")]
-  WebsocketError(tungstenite::Error),
+  WebsocketError(Box<tungstenite::Error>),
   #[error("message received from twitch was not a session welcome")]
   IncorrectMessage,
   #[error("failed to acquire a welcome message from twitch")]
@@ -36,7 +36,7 @@ impl From<serde_json::Error> for EventsubError {
 
 impl From<tungstenite::Error> for EventsubError {
   fn from(e: tungstenite::Error) -> Self {
-    Self::WebsocketError(e)
+    Self::WebsocketError(Box::new(e))
   }
 }
 
@@ -116,12 +116,17 @@ impl EventsubConnection {
     };
 
     match message.payload {
-      Payload::Notification { subscription: _, event } => self.sender.send(event).unwrap_or(()),
+      Payload::Notification { .. } => match message.into_event() {
+        Some(Ok(event)) => self.sender.send(event).unwrap_or(()),
+        Some(Err(e)) => error!("Failed to parse event: {e}"),
+        None => (),
+      },
       Payload::Reconnect { session } => self.reconnect(session),
-      Payload::Revocation { subscription: _ } => self
-        .websocket
-        .send(tungstenite::Message::Close(None))
-        .unwrap_or(error!("Failed to close websocket on revocation")),
+      Payload::Revocation { subscription: _ } => {
+        if self.websocket.send(tungstenite::Message::Close(None)).is_err() {
+          error!("Failed to close websocket on revocation")
+        }
+      }
       _ => warn!("Received unknown notification on websocket: {:?}", message),
     }
   }
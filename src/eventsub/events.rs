@@ -3,6 +3,7 @@ mod channel;
 mod charity;
 mod chat;
 mod goal;
+mod guest_star;
 mod hypetrain;
 mod moderation;
 mod polls;
@@ -20,12 +21,14 @@ pub use channel::*;
 pub use charity::*;
 pub use chat::*;
 pub use goal::*;
+pub use guest_star::*;
 pub use hypetrain::*;
 pub use moderation::*;
 pub use polls::*;
 pub use prediction::*;
 pub use rewards::*;
 use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Value, from_value};
 use serde_with::with_prefix;
 pub use shared_chat::*;
 pub use subscription::*;
@@ -37,11 +40,21 @@ use super::Subscription;
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub(super) struct EventsubMessage {
+pub(crate) struct EventsubMessage {
   pub(super) metadata: Metadata,
   pub(super) payload:  Payload,
 }
 
+impl EventsubMessage {
+  /// The event of a notification, `None` for any other message.
+  pub(crate) fn into_event(self) -> Option<Result<Event, serde_json::Error>> {
+    let Payload::Notification { event, .. } = self.payload else { return None };
+    let subscription_type = self.metadata.subscription_type.unwrap_or_default();
+
+    Some(Event::from_type(&subscription_type, event))
+  }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Metadata {
   pub(super) message_id:           String,
@@ -64,7 +77,7 @@ pub(super) enum EventsubMessageType {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 pub(super) enum Payload {
-  Notification { subscription: Subscription, event: Event },
+  Notification { subscription: Subscription, event: Value },
   Revocation { subscription: Subscription },
   Reconnect { session: Reconnect },
   Welcome { session: Welcome },
@@ -92,7 +105,9 @@ pub(super) struct Welcome {
 // Events
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+// Events carry no type of their own and many only differ in optional fields, so they are decoded
+// by the subscription type of their notification instead of trying each variant in turn.
+#[derive(Serialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum Event {
   AutomodMessageHold(AutomodMessageHold),
@@ -155,6 +170,10 @@ pub enum Event {
   HypeTrainBegin(HypeTrainBegin),
   HypeTrainProgress(HypeTrainProgress),
   HypeTrainEnd(HypeTrainEnd),
+  GuestStarSessionBegin(GuestStarSessionBegin),
+  GuestStarSessionEnd(GuestStarSessionEnd),
+  GuestStarGuestUpdate(GuestStarGuestUpdate),
+  GuestStarSettingsUpdate(GuestStarSettingsUpdate),
   ShieldModeBegin(ShieldModeBegin),
   ShieldModeEnd(ShieldModeEnd),
   ShoutoutCreate(ShoutoutCreate),
@@ -165,14 +184,101 @@ pub enum Event {
   WhisperReceived(WhisperReceived),
 }
 
+impl Event {
+  pub fn from_type(subscription_type: &str, event: Value) -> Result<Self, serde_json::Error> {
+    let event = match subscription_type {
+      "automod.message.hold" => Self::AutomodMessageHold(from_value(event)?),
+      "automod.message.update" => Self::AutomodMessageUpdate(from_value(event)?),
+      "automod.settings.update" => Self::AutomodSettingsUpdate(from_value(event)?),
+      "automod.terms.update" => Self::AutomodTermsUpdate(from_value(event)?),
+      "channel.update" => Self::ChannelUpdate(from_value(event)?),
+      "channel.follow" => Self::Follow(from_value(event)?),
+      "channel.ad_break.begin" => Self::AdBreakBegin(from_value(event)?),
+      "channel.chat.clear" => Self::ChatClear(from_value(event)?),
+      "channel.chat.clear_user_messages" => Self::ChatClearUserMessages(from_value(event)?),
+      "channel.chat.message" => Self::ChatMessage(from_value(event)?),
+      "channel.chat.message_delete" => Self::ChatMessageDelete(from_value(event)?),
+      "channel.chat.notification" => Self::ChatNotification(from_value(event)?),
+      "channel.chat_settings.update" => Self::ChatSettingsUpdate(from_value(event)?),
+      "channel.chat.user_message_hold" => Self::ChatUserMessageHold(from_value(event)?),
+      "channel.chat.user_message_update" => Self::ChatUserMessageUpdate(from_value(event)?),
+      "channel.shared_chat.begin" => Self::SharedChatSessionBegin(from_value(event)?),
+      "channel.shared_chat.update" => Self::SharedChatSessionUpdate(from_value(event)?),
+      "channel.shared_chat.end" => Self::SharedChatSessionEnd(from_value(event)?),
+      "channel.subscribe" => Self::Subscribe(from_value(event)?),
+      "channel.subscription.end" => Self::SubscriptionEnd(from_value(event)?),
+      "channel.subscription.gift" => Self::SubscriptionGift(from_value(event)?),
+      "channel.subscription.message" => Self::SubscriptionMessage(from_value(event)?),
+      "channel.cheer" => Self::Cheer(from_value(event)?),
+      "channel.raid" => Self::Raid(from_value(event)?),
+      "channel.ban" => Self::Ban(from_value(event)?),
+      "channel.unban" => Self::Unban(from_value(event)?),
+      "channel.unban_request.create" => Self::UnbanRequestCreate(from_value(event)?),
+      "channel.unban_request.resolve" => Self::UnbanRequestResolve(from_value(event)?),
+      "channel.moderate" => Self::Moderate(from_value(event)?),
+      "channel.moderator.add" => Self::ModeratorAdd(from_value(event)?),
+      "channel.moderator.remove" => Self::ModeratorRemove(from_value(event)?),
+      "channel.channel_points_automatic_reward_redemption.add" =>
+        Self::PointsAutomaticRewardRedemption(from_value(event)?),
+      "channel.channel_points_custom_reward.add" => Self::PointsCustomRewardAdd(from_value(event)?),
+      "channel.channel_points_custom_reward.update" =>
+        Self::PointsCustomRewardUpdate(from_value(event)?),
+      "channel.channel_points_custom_reward.remove" =>
+        Self::PointsCustomRewardRemove(from_value(event)?),
+      "channel.channel_points_custom_reward_redemption.add" =>
+        Self::PointsCustomRewardRedemptionAdd(from_value(event)?),
+      "channel.channel_points_custom_reward_redemption.update" =>
+        Self::PointsCustomRewardRedemptionUpdate(from_value(event)?),
+      "channel.poll.begin" => Self::PollBegin(from_value(event)?),
+      "channel.poll.progress" => Self::PollProgress(from_value(event)?),
+      "channel.poll.end" => Self::PollEnd(from_value(event)?),
+      "channel.prediction.begin" => Self::PredictionBegin(from_value(event)?),
+      "channel.prediction.progress" => Self::PredictionProgress(from_value(event)?),
+      "channel.prediction.lock" => Self::PredictionLock(from_value(event)?),
+      "channel.prediction.end" => Self::PredictionEnd(from_value(event)?),
+      "channel.suspicious_user.message" => Self::SuspiciousUserMessage(from_value(event)?),
+      "channel.suspicious_user.update" => Self::SuspiciousUserUpdate(from_value(event)?),
+      "channel.vip.add" => Self::VIPAdd(from_value(event)?),
+      "channel.vip.remove" => Self::VIPRemove(from_value(event)?),
+      "channel.warning.acknowledge" => Self::WarningAcknowledge(from_value(event)?),
+      "channel.warning.send" => Self::WarningSend(from_value(event)?),
+      "channel.charity_campaign.donate" => Self::CharityDonation(from_value(event)?),
+      "channel.charity_campaign.start" => Self::CharityCampaignStart(from_value(event)?),
+      "channel.charity_campaign.progress" => Self::CharityCampaignProgress(from_value(event)?),
+      "channel.charity_campaign.stop" => Self::CharityCampaignStop(from_value(event)?),
+      "channel.goal.begin" => Self::GoalBegin(from_value(event)?),
+      "channel.goal.progress" => Self::GoalProgress(from_value(event)?),
+      "channel.goal.end" => Self::GoalEnd(from_value(event)?),
+      "channel.hype_train.begin" => Self::HypeTrainBegin(from_value(event)?),
+      "channel.hype_train.progress" => Self::HypeTrainProgress(from_value(event)?),
+      "channel.hype_train.end" => Self::HypeTrainEnd(from_value(event)?),
+      "channel.guest_star_session.begin" => Self::GuestStarSessionBegin(from_value(event)?),
+      "channel.guest_star_session.end" => Self::GuestStarSessionEnd(from_value(event)?),
+      "channel.guest_star_guest.update" => Self::GuestStarGuestUpdate(from_value(event)?),
+      "channel.guest_star_settings.update" => Self::GuestStarSettingsUpdate(from_value(event)?),
+      "channel.shield_mode.begin" => Self::ShieldModeBegin(from_value(event)?),
+      "channel.shield_mode.end" => Self::ShieldModeEnd(from_value(event)?),
+      "channel.shoutout.create" => Self::ShoutoutCreate(from_value(event)?),
+      "channel.shoutout.receive" => Self::ShoutoutReceived(from_value(event)?),
+      "stream.online" => Self::StreamOnline(from_value(event)?),
+      "stream.offline" => Self::StreamOffline(from_value(event)?),
+      "user.update" => Self::UserUpdate(from_value(event)?),
+      "user.whisper.message" => Self::WhisperReceived(from_value(event)?),
+      _ => return Err(serde::de::Error::custom(format!("unknown event type {subscription_type}"))),
+    };
+
+    Ok(event)
+  }
+}
+
 // Common event sub-components
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct User {
-  user_id:    String,
-  user_login: String,
-  user_name:  String,
+  pub user_id:    String,
+  pub user_login: String,
+  pub user_name:  String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -257,6 +363,7 @@ with_prefix!(broadcaster "broadcaster_");
 with_prefix!(source_broadcaster "source_broadcaster_");
 with_prefix!(owner_broadcaster "owner_broadcaster_");
 with_prefix!(host_broadcaster "host_broadcaster_");
+with_prefix!(host "host_");
 with_prefix!(from_broadcaster "from_broadcaster_");
 with_prefix!(to_broadcaster "to_broadcaster_");
 with_prefix!(moderator "moderator_");
@@ -268,7 +375,89 @@ with_prefix!(thread "thread_");
 with_prefix!(to "to_");
 with_prefix!(from "from_");
 
-fn maybe_string<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+// Maps `User` onto fields with arbitrary names. Helix prefixes users as `moderator_id`,
+// `moderator_login` and `moderator_name`, which `with_prefix!` can't express, and eventsub sends
+// users that may be absent with every field set to null, which the `option` module maps to `None`.
+macro_rules! prefixed_user {
+  ($module:ident $id:literal $login:literal $name:literal) => {
+    #[allow(dead_code)]
+    pub(crate) mod $module {
+      use serde::de::Error;
+      use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+      use crate::User;
+
+      #[derive(Serialize, Deserialize, Default)]
+      struct PrefixedUser {
+        #[serde(rename = $id, default)]
+        id:    Option<String>,
+        #[serde(rename = $login, default)]
+        login: Option<String>,
+        #[serde(rename = $name, default)]
+        name:  Option<String>,
+      }
+
+      impl PrefixedUser {
+        fn new(user: &User) -> Self {
+          Self {
+            id:    Some(user.user_id.clone()),
+            login: Some(user.user_login.clone()),
+            name:  Some(user.user_name.clone()),
+          }
+        }
+
+        fn into_user<E: Error>(self) -> Result<User, E> {
+          Ok(User {
+            user_id:    self.id.ok_or_else(|| E::missing_field($id))?,
+            user_login: self.login.ok_or_else(|| E::missing_field($login))?,
+            user_name:  self.name.ok_or_else(|| E::missing_field($name))?,
+          })
+        }
+      }
+
+      pub(crate) fn serialize<S: Serializer>(
+        user: &User,
+        serializer: S,
+      ) -> Result<S::Ok, S::Error> {
+        PrefixedUser::new(user).serialize(serializer)
+      }
+
+      pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+      ) -> Result<User, D::Error> {
+        PrefixedUser::deserialize(deserializer)?.into_user()
+      }
+
+      pub(crate) mod option {
+        use super::*;
+
+        pub(crate) fn serialize<S: Serializer>(
+          user: &Option<User>,
+          serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+          user.as_ref().map(PrefixedUser::new).unwrap_or_default().serialize(serializer)
+        }
+
+        pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+          deserializer: D,
+        ) -> Result<Option<User>, D::Error> {
+          let user = PrefixedUser::deserialize(deserializer)?;
+          match user.id.as_deref() {
+            None | Some("") => Ok(None),
+            Some(_) => user.into_user().map(Some),
+          }
+        }
+      }
+    }
+  };
+}
+
+pub(crate) use prefixed_user;
+
+prefixed_user!(nullable_moderator "moderator_user_id" "moderator_user_login" "moderator_user_name");
+prefixed_user!(nullable_guest "guest_user_id" "guest_user_login" "guest_user_name");
+
+pub(crate) fn maybe_string<'de, D, T>(deserializer: D) -> Result<T, D::Error>
 where
   T: Deserialize<'de> + FromStr,
   D: Deserializer<'de>,
@@ -287,6 +476,46 @@ where
   }
 }
 
+pub(crate) fn maybe_null<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+  T: Deserialize<'de> + Default,
+  D: Deserializer<'de>,
+{
+  Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+// Wraps an event in a websocket notification and decodes it the way the eventsub connection does.
+#[cfg(test)]
+pub(crate) fn notification(subscription_type: &str, event: &str) -> Event {
+  let message = format!(
+    r#"{{
+      "metadata": {{
+        "message_id": "befa7b53-d79d-478f-86b9-120f112b044e",
+        "message_type": "notification",
+        "message_timestamp": "2022-11-16T10:11:12.464757833Z",
+        "subscription_type": "{subscription_type}",
+        "subscription_version": "1"
+      }},
+      "payload": {{
+        "subscription": {{
+          "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+          "status": "enabled",
+          "type": "{subscription_type}",
+          "version": "1",
+          "condition": {{}},
+          "transport": {{
+            "method": "websocket",
+            "session_id": "AQoQexAWVYKSTIu4ec_2VAxyuhAB"
+          }}
+        }},
+        "event": {event}
+      }}
+    }}"#
+  );
+
+  serde_json::from_str::<EventsubMessage>(&message).unwrap().into_event().unwrap().unwrap()
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -329,6 +558,22 @@ mod tests {
         }
       }
     }"##;
-    serde_json::from_str::<EventsubMessage>(event).unwrap();
+    let message = serde_json::from_str::<EventsubMessage>(event).unwrap();
+    assert!(matches!(message.into_event(), Some(Ok(Event::Follow(_)))));
+  }
+
+  #[test]
+  fn event_dispatch() {
+    let event = r#"
+    {
+      "broadcaster_user_id": "1337",
+      "broadcaster_user_login": "cool_user",
+      "broadcaster_user_name": "Cool_User",
+      "user_id": "1234",
+      "user_login": "mod_user",
+      "user_name": "Mod_User"
+    }"#;
+    assert!(matches!(notification("channel.moderator.add", event), Event::ModeratorAdd(_)));
+    assert!(matches!(notification("channel.vip.remove", event), Event::VIPRemove(_)));
   }
 }
@@ -57,6 +57,7 @@ pub struct GoalEnd {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum GoalType {
+  #[serde(alias = "follower")]
   Follow,
   Subscription,
   SubscriptionCount,
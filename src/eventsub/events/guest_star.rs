@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+
+use super::{User, broadcaster, host, moderator, nullable_guest, nullable_moderator};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GuestStarSessionBegin {
+  #[serde(flatten, with = "broadcaster")]
+  pub broadcaster: User,
+  #[serde(flatten, with = "moderator")]
+  pub moderator:   User,
+  pub session_id:  String,
+  pub started_at:  String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GuestStarSessionEnd {
+  #[serde(flatten, with = "broadcaster")]
+  pub broadcaster: User,
+  #[serde(flatten, with = "moderator")]
+  pub moderator:   User,
+  pub session_id:  String,
+  pub started_at:  String,
+  pub ended_at:    String,
+  #[serde(flatten, with = "host")]
+  pub host:        User,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GuestStarGuestUpdate {
+  #[serde(flatten, with = "broadcaster")]
+  pub broadcaster:        User,
+  pub session_id:         String,
+  #[serde(flatten, with = "nullable_moderator::option")]
+  pub moderator:          Option<User>,
+  #[serde(flatten, with = "nullable_guest::option")]
+  pub guest:              Option<User>,
+  pub slot_id:            Option<String>,
+  pub state:              Option<GuestState>,
+  #[serde(flatten, with = "host")]
+  pub host:               User,
+  pub host_video_enabled: Option<bool>,
+  pub host_audio_enabled: Option<bool>,
+  pub host_volume:        Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GuestStarSettingsUpdate {
+  #[serde(flatten, with = "broadcaster")]
+  pub broadcaster: User,
+  pub is_moderator_send_live_enabled: bool,
+  pub slot_count: u32,
+  pub is_browser_source_audio_enabled: bool,
+  pub group_layout: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GuestState {
+  Invited,
+  Accepted,
+  Ready,
+  Backstage,
+  Live,
+  Removed,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Event;
+  use crate::eventsub::events::notification;
+
+  #[test]
+  fn channel_guest_star_session_begin() {
+    let event = r#"
+    {
+      "broadcaster_user_id": "1337",
+      "broadcaster_user_name": "Cool_User",
+      "broadcaster_user_login": "cool_user",
+      "moderator_user_id": "1338",
+      "moderator_user_name": "Cool_Mod",
+      "moderator_user_login": "cool_mod",
+      "session_id": "2KFRQbFtpmfyD3IevNRnCzOPRJI",
+      "started_at": "2023-04-11T16:20:03.17106713Z"
+    }"#;
+    let event = notification("channel.guest_star_session.begin", event);
+    assert!(matches!(event, Event::GuestStarSessionBegin(_)));
+  }
+
+  #[test]
+  fn channel_guest_star_session_end() {
+    let event = r#"
+    {
+      "broadcaster_user_id": "1337",
+      "broadcaster_user_name": "Cool_User",
+      "broadcaster_user_login": "cool_user",
+      "moderator_user_id": "1338",
+      "moderator_user_name": "Cool_Mod",
+      "moderator_user_login": "cool_mod",
+      "session_id": "2KFRQbFtpmfyD3IevNRnCzOPRJI",
+      "started_at": "2023-04-11T16:20:03.17106713Z",
+      "ended_at": "2023-04-11T17:51:29.153485Z",
+      "host_user_id": "1337",
+      "host_user_name": "Cool_User",
+      "host_user_login": "cool_user"
+    }"#;
+    serde_json::from_str::<GuestStarSessionEnd>(event).unwrap();
+  }
+
+  #[test]
+  fn channel_guest_star_guest_update() {
+    let event = r#"
+    {
+      "broadcaster_user_id": "1337",
+      "broadcaster_user_name": "Cool_User",
+      "broadcaster_user_login": "cool_user",
+      "session_id": "2KFRQbFtpmfyD3IevNRnCzOPRJI",
+      "moderator_user_id": "1312",
+      "moderator_user_name": "Cool_Mod",
+      "moderator_user_login": "cool_mod",
+      "guest_user_id": "1234",
+      "guest_user_name": "Cool_Guest",
+      "guest_user_login": "cool_guest",
+      "slot_id": "1",
+      "state": "live",
+      "host_user_id": "4242",
+      "host_user_name": "A_Host",
+      "host_user_login": "a_host",
+      "host_video_enabled": true,
+      "host_audio_enabled": true,
+      "host_volume": 100
+    }"#;
+    let event = serde_json::from_str::<GuestStarGuestUpdate>(event).unwrap();
+    assert_eq!(event.state, Some(GuestState::Live));
+    assert_eq!(event.guest.unwrap().user_login, "cool_guest");
+  }
+
+  #[test]
+  fn channel_guest_star_guest_update_removed() {
+    let event = r#"
+    {
+      "broadcaster_user_id": "1337",
+      "broadcaster_user_name": "Cool_User",
+      "broadcaster_user_login": "cool_user",
+      "session_id": "2KFRQbFtpmfyD3IevNRnCzOPRJI",
+      "moderator_user_id": null,
+      "moderator_user_name": null,
+      "moderator_user_login": null,
+      "guest_user_id": null,
+      "guest_user_name": null,
+      "guest_user_login": null,
+      "slot_id": null,
+      "state": null,
+      "host_user_id": "4242",
+      "host_user_name": "A_Host",
+      "host_user_login": "a_host",
+      "host_video_enabled": null,
+      "host_audio_enabled": null,
+      "host_volume": null
+    }"#;
+    let event = serde_json::from_str::<GuestStarGuestUpdate>(event).unwrap();
+    assert!(event.moderator.is_none() && event.guest.is_none());
+  }
+
+  #[test]
+  fn channel_guest_star_settings_update() {
+    let event = r#"
+    {
+      "broadcaster_user_id": "1337",
+      "broadcaster_user_name": "Cool_User",
+      "broadcaster_user_login": "cool_user",
+      "is_moderator_send_live_enabled": true,
+      "slot_count": 5,
+      "is_browser_source_audio_enabled": true,
+      "group_layout": "tiled"
+    }"#;
+    let event = notification("channel.guest_star_settings.update", event);
+    assert!(matches!(event, Event::GuestStarSettingsUpdate(_)));
+  }
+}
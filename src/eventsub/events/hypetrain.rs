@@ -77,8 +77,11 @@ pub struct Contribution {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum ContributionType {
+  #[serde(alias = "BITS")]
   Bits,
+  #[serde(alias = "SUBS")]
   Subscription,
+  #[serde(alias = "OTHER")]
   Other,
 }
 
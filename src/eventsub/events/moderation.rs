@@ -132,9 +132,11 @@ pub struct ShieldModeEnd {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum UnbanStatus {
+  Pending,
   Approved,
   Canceled,
   Denied,
+  Acknowledged,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -317,11 +319,14 @@ pub struct UnbanRequestAction {
   pub moderator_message: String,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum TrustStatus {
+  #[serde(alias = "NO_TREATMENT")]
   None,
+  #[serde(alias = "ACTIVE_MONITORING")]
   ActiveMonitoring,
+  #[serde(alias = "RESTRICTED")]
   Restricted,
 }
 
@@ -69,9 +69,18 @@ pub struct ExtraVotes {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum PollStatus {
+  #[serde(alias = "ACTIVE")]
+  Active,
+  #[serde(alias = "COMPLETED")]
   Completed,
+  #[serde(alias = "ARCHIVED")]
   Archived,
+  #[serde(alias = "TERMINATED")]
   Terminated,
+  #[serde(alias = "MODERATED")]
+  Moderated,
+  #[serde(alias = "INVALID")]
+  Invalid,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::{User, broadcaster, maybe_string};
+use super::{User, broadcaster, maybe_null, maybe_string};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -69,6 +69,7 @@ pub struct Outcome {
   pub users:          u64,
   #[serde(deserialize_with = "maybe_string", default)]
   pub channel_points: u64,
+  #[serde(deserialize_with = "maybe_null", default)]
   pub top_predictors: Vec<Predictor>,
 }
 
@@ -84,7 +85,13 @@ pub struct Predictor {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum PredictionStatus {
+  #[serde(alias = "ACTIVE")]
+  Active,
+  #[serde(alias = "LOCKED")]
+  Locked,
+  #[serde(alias = "RESOLVED")]
   Resolved,
+  #[serde(alias = "CANCELED")]
   Canceled,
 }
 
@@ -164,7 +164,11 @@ pub struct UnlockedEmote {
 pub struct MaxPerStream {
   #[serde(deserialize_with = "maybe_string")]
   pub is_enabled: bool,
-  #[serde(deserialize_with = "maybe_string")]
+  #[serde(
+    deserialize_with = "maybe_string",
+    alias = "max_per_stream",
+    alias = "max_per_user_per_stream"
+  )]
   pub value:      u64,
 }
 
@@ -179,15 +183,18 @@ pub struct Image {
 pub struct GlobalCooldown {
   #[serde(deserialize_with = "maybe_string")]
   pub is_enabled: bool,
-  #[serde(deserialize_with = "maybe_string")]
+  #[serde(deserialize_with = "maybe_string", alias = "global_cooldown_seconds")]
   pub seconds:    u64,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum RedeemStatus {
+  #[serde(alias = "UNFULFILLED")]
   Unfulfilled,
+  #[serde(alias = "FULFILLED")]
   Fulfilled,
+  #[serde(alias = "CANCELED")]
   Canceled,
   Unknown,
 }
@@ -79,6 +79,10 @@ pub enum SubscriptionType {
   HypeTrainBegin,
   HypeTrainProgress,
   HypeTrainEnd,
+  GuestStarSessionBegin,
+  GuestStarSessionEnd,
+  GuestStarGuestUpdate,
+  GuestStarSettingsUpdate,
   ShieldModeBegin,
   ShieldModeEnd,
   ShoutoutCreate,
@@ -92,7 +96,7 @@ pub enum SubscriptionType {
 impl SubscriptionType {
   pub fn build_subscription(&self, session_id: &str, conditions: &Conditions) -> Subscription {
     let (subscription_type, version) = self.details();
-    let condition = self.conditions(&conditions);
+    let condition = self.conditions(conditions);
     let transport = json!({
       "method": "websocket",
       "session_id": session_id
@@ -125,8 +129,8 @@ impl SubscriptionType {
       Self::SubscriptionEnd => ("channel.subscription.end", "1"),
       Self::SubscriptionGift => ("channel.subscription.gift", "1"),
       Self::SubscriptionMessage => ("channel.subscription.message", "1"),
-      Self::Cheer => ("channel.ChannelCheer", "1"),
-      Self::Raid(_) => ("channel.ChannelRaid", "1"),
+      Self::Cheer => ("channel.cheer", "1"),
+      Self::Raid(_) => ("channel.raid", "1"),
       Self::Ban => ("channel.ban", "1"),
       Self::Unban => ("channel.unban", "1"),
       Self::UnbanRequestCreate => ("channel.unban_request.create", "1"),
@@ -166,6 +170,10 @@ impl SubscriptionType {
       Self::HypeTrainBegin => ("channel.hype_train.begin", "1"),
       Self::HypeTrainProgress => ("channel.hype_train.progress", "1"),
       Self::HypeTrainEnd => ("channel.hype_train.end", "1"),
+      Self::GuestStarSessionBegin => ("channel.guest_star_session.begin", "beta"),
+      Self::GuestStarSessionEnd => ("channel.guest_star_session.end", "beta"),
+      Self::GuestStarGuestUpdate => ("channel.guest_star_guest.update", "beta"),
+      Self::GuestStarSettingsUpdate => ("channel.guest_star_settings.update", "beta"),
       Self::ShieldModeBegin => ("channel.shield_mode.begin", "1"),
       Self::ShieldModeEnd => ("channel.shield_mode.end", "1"),
       Self::ShoutoutCreate => ("channel.shoutout.create", "1"),
@@ -251,6 +259,10 @@ impl SubscriptionType {
       Self::HypeTrainBegin => vec![cond.broadcaster_user_id()],
       Self::HypeTrainProgress => vec![cond.broadcaster_user_id()],
       Self::HypeTrainEnd => vec![cond.broadcaster_user_id()],
+      Self::GuestStarSessionBegin => vec![cond.broadcaster_user_id(), cond.moderator_user_id()],
+      Self::GuestStarSessionEnd => vec![cond.broadcaster_user_id(), cond.moderator_user_id()],
+      Self::GuestStarGuestUpdate => vec![cond.broadcaster_user_id(), cond.moderator_user_id()],
+      Self::GuestStarSettingsUpdate => vec![cond.broadcaster_user_id(), cond.moderator_user_id()],
       Self::ShieldModeBegin => vec![cond.broadcaster_user_id(), cond.moderator_user_id()],
       Self::ShieldModeEnd => vec![cond.broadcaster_user_id(), cond.moderator_user_id()],
       Self::ShoutoutCreate => vec![cond.broadcaster_user_id(), cond.moderator_user_id()],
@@ -287,6 +299,7 @@ impl Conditions {
     ("reward_id".to_string(), json!(id))
   }
 
+  #[allow(clippy::wrong_self_convention)]
   fn from_broadcaster_user_id(&self) -> (String, Value) {
     ("from_broadcaster_user_id".to_string(), json!(self.broadcaster_id))
   }
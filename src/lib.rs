@@ -4,8 +4,32 @@ mod eventsub;
 #[cfg(feature = "token-helpers")]
 mod token;
 
-pub use api::responses;
-use api::{APIEndpoint, APIError, TwitchAPI};
+pub use api::ads::*;
+pub use api::automod::*;
+pub use api::bits::*;
+pub use api::blocked_terms::*;
+pub use api::channels::*;
+pub use api::charity::*;
+pub use api::clips::*;
+pub use api::followers::*;
+pub use api::games::*;
+pub use api::goals::*;
+pub use api::guest_star::*;
+pub use api::hype_train::*;
+pub use api::moderation::*;
+pub use api::polls::*;
+pub use api::predictions::*;
+pub use api::raids::*;
+pub use api::rewards::*;
+pub use api::roles::*;
+pub use api::schedule::*;
+pub use api::streams::*;
+pub use api::subscriptions::*;
+pub use api::teams::*;
+pub use api::users::*;
+pub use api::videos::*;
+use api::{APIEndpoint, Method, TwitchAPI};
+pub use api::{APIError, responses};
 pub use eventsub::events::*;
 pub use eventsub::{Conditions, Eventsub, EventsubError, Raid, Subscription, SubscriptionType};
 use thiserror::Error;
@@ -58,15 +82,15 @@ impl Twitch {
     let response = ureq::post(APIEndpoint::Token.endpoint())
       .set("Content-Type", "application/x-www-form-urlencoded")
       .send_string(&request_body)
-      .map_err(|e| APIError::from(e))?
+      .map_err(APIError::from)?
       .into_json::<responses::NewAccessTokenResponse>()
-      .map_err(|e| APIError::from(e))?;
+      .map_err(APIError::from)?;
 
     Ok((
       response.access_token,
       response
         .refresh_token
-        .ok_or(APIError::ConnectionError(format!("refresh token missing from response")))?,
+        .ok_or(APIError::ConnectionError("refresh token missing from response".to_owned()))?,
     ))
   }
 
@@ -78,7 +102,7 @@ impl Twitch {
     &self,
     subscription: Subscription,
   ) -> Result<Subscription, TwitchError> {
-    Ok(self.api.post::<Subscription, _>(APIEndpoint::Subscriptions, subscription)?)
+    Ok(self.api.request(Method::Post, APIEndpoint::Subscriptions, &[]).json(subscription).one()?)
   }
 }
 
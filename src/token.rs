@@ -2,9 +2,9 @@ use std::io::{BufRead, BufReader, Write};
 use std::net::{TcpListener, TcpStream};
 
 use bitmask_enum::bitmask;
-use twitch_eventsub_structs::NewAccessTokenResponse;
 
 use crate::SubscriptionType;
+use crate::responses::NewAccessTokenResponse;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -153,6 +153,7 @@ pub enum Scope {
   ModeratorReadShoutouts,
   ModeratorManageShoutouts,
   ModeratorReadSuspiciousUsers,
+  ModeratorManageSuspiciousUsers,
   ModeratorReadUnbanRequests,
   ModeratorManageUnbanRequests,
   ModeratorReadVips,
@@ -240,6 +241,7 @@ impl Scope {
       (Self::ModeratorReadShoutouts, "moderator%3Aread%3Ashoutouts"),
       (Self::ModeratorManageShoutouts, "moderator%3Amanage%3Ashoutouts"),
       (Self::ModeratorReadSuspiciousUsers, "moderator%3Aread%3Asuspicious_users"),
+      (Self::ModeratorManageSuspiciousUsers, "moderator%3Amanage%3Asuspicious_users"),
       (Self::ModeratorReadUnbanRequests, "moderator%3Aread%3Aunban_requests"),
       (Self::ModeratorManageUnbanRequests, "moderator%3Amanage%3Aunban_requests"),
       (Self::ModeratorReadVips, "moderator%3Aread%3Avips"),
@@ -337,6 +339,10 @@ impl From<SubscriptionType> for Scope {
       SubscriptionType::HypeTrainBegin => Scope::ChannelReadHypeTrain,
       SubscriptionType::HypeTrainProgress => Scope::ChannelReadHypeTrain,
       SubscriptionType::HypeTrainEnd => Scope::ChannelReadHypeTrain,
+      SubscriptionType::GuestStarSessionBegin => Scope::ModeratorReadGuestStar,
+      SubscriptionType::GuestStarSessionEnd => Scope::ModeratorReadGuestStar,
+      SubscriptionType::GuestStarGuestUpdate => Scope::ModeratorReadGuestStar,
+      SubscriptionType::GuestStarSettingsUpdate => Scope::ModeratorReadGuestStar,
       SubscriptionType::ShieldModeBegin => Scope::ModeratorReadShieldMode,
       SubscriptionType::ShieldModeEnd => Scope::ModeratorReadShieldMode,
       SubscriptionType::ShoutoutCreate => Scope::ModeratorReadShoutouts,